@@ -0,0 +1,115 @@
+//! # Async Engine
+//!
+//! An [`Engine`] that persists proposals through an [`AsyncProposalStore`]
+//! after every mutation, so services can back it with a network database
+//! without the core depending on one.
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::engine::Engine;
+use crate::models::proposal::Proposal;
+use crate::models::vote::{Vote, VoteError};
+use crate::storage::AsyncProposalStore;
+
+/// A consensus engine that saves proposals to an async store as they change.
+///
+/// Reads go straight to the wrapped [`Engine`]; every method that mutates a
+/// proposal awaits a save of it before returning. The engine is `Send`
+/// whenever the store is, so its futures can be spawned onto a
+/// multi-threaded runtime.
+pub struct AsyncEngine<S> {
+    engine: Engine,
+    store: S,
+}
+
+impl<S: AsyncProposalStore> AsyncEngine<S> {
+    /// Creates an empty engine that persists to the given store.
+    ///
+    /// # Arguments
+    /// * `store` - Backend to persist proposals to
+    pub fn new(store: S) -> Self {
+        Self {
+            engine: Engine::new(),
+            store,
+        }
+    }
+
+    /// Returns the wrapped engine.
+    pub fn engine(&self) -> &Engine {
+        &self.engine
+    }
+
+    /// Returns the backing store.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Adds a new proposal and saves it.
+    ///
+    /// # Arguments
+    /// * `proposal` - The proposal to add
+    pub async fn add_proposal(&mut self, proposal: Proposal) {
+        self.store.save(&proposal).await;
+        self.engine.add_proposal(proposal);
+    }
+
+    /// Loads a proposal from the store into the engine.
+    ///
+    /// A proposal the engine already holds is replaced by the stored copy.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to load
+    ///
+    /// # Returns
+    /// `true` if the store held the proposal
+    pub async fn load(&mut self, proposal_id: Uuid) -> bool {
+        let Some(proposal) = self.store.load(proposal_id).await else {
+            return false;
+        };
+        self.engine.remove_proposal(proposal_id);
+        self.engine.add_proposal(proposal);
+        true
+    }
+
+    /// Casts a vote and saves the updated proposal.
+    ///
+    /// Nothing is saved if the vote is refused.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to vote on
+    /// * `vote` - The vote to cast
+    ///
+    /// # Returns
+    /// `Ok(())` if the vote was cast, otherwise the reason it was refused
+    pub async fn cast_vote(&mut self, proposal_id: Uuid, vote: Vote) -> Result<(), VoteError> {
+        self.engine.try_cast_vote(proposal_id, vote)?;
+        if let Some(proposal) = self.engine.get_proposal(proposal_id) {
+            self.store.save(proposal).await;
+        }
+        Ok(())
+    }
+
+    /// Evaluates all proposals and saves every one of them.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp for evaluation
+    pub async fn evaluate_all(&mut self, now: DateTime<Utc>) {
+        self.engine.evaluate_all(now);
+        for proposal in &self.engine.proposals {
+            self.store.save(proposal).await;
+        }
+    }
+
+    /// Removes a proposal from the engine and deletes it from the store.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to remove
+    ///
+    /// # Returns
+    /// The removed proposal, if the engine held it
+    pub async fn remove_proposal(&mut self, proposal_id: Uuid) -> Option<Proposal> {
+        self.store.delete(proposal_id).await;
+        self.engine.remove_proposal(proposal_id)
+    }
+}
@@ -0,0 +1,169 @@
+//! # Concurrent Engine
+//!
+//! A thread-safe counterpart to [`Engine`] for servers that cast votes from
+//! many threads at once.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::engine::Engine;
+use crate::models::proposal::{Proposal, ProposalStatus};
+use crate::models::vote::{Vote, VoteError};
+
+/// A consensus engine that can be shared between threads.
+///
+/// Each proposal sits behind its own lock, so votes on different proposals
+/// never contend with each other. The map of proposals is only locked
+/// exclusively when proposals are added.
+#[derive(Default)]
+pub struct ConcurrentEngine {
+    proposals: RwLock<HashMap<Uuid, Mutex<Proposal>>>,
+}
+
+impl ConcurrentEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a new proposal to the engine.
+    ///
+    /// # Arguments
+    /// * `proposal` - The proposal to add
+    pub fn add_proposal(&self, proposal: Proposal) {
+        self.proposals
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(proposal.id, Mutex::new(proposal));
+    }
+
+    /// Attempts to cast a vote on a proposal.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to vote on
+    /// * `vote` - The vote to cast
+    ///
+    /// # Returns
+    /// `true` if the vote was successfully cast, `false` if it was refused
+    /// for any of the reasons reported by [`ConcurrentEngine::try_cast_vote`]
+    pub fn cast_vote(&self, proposal_id: Uuid, vote: Vote) -> bool {
+        self.try_cast_vote(proposal_id, vote).is_ok()
+    }
+
+    /// Attempts to cast a vote on a proposal, reporting why it was refused.
+    ///
+    /// Applies the same checks as [`Engine::try_cast_vote`]: the proposal
+    /// must be pending with every dependency accepted, and the vote must be
+    /// dated inside the window. Only one proposal is locked at a time, so
+    /// votes on different proposals don't contend.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to vote on
+    /// * `vote` - The vote to cast
+    ///
+    /// # Returns
+    /// `Ok(())` if the vote was cast, otherwise the reason it was refused
+    pub fn try_cast_vote(&self, proposal_id: Uuid, vote: Vote) -> Result<(), VoteError> {
+        let proposals = self
+            .proposals
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        let proposal = proposals
+            .get(&proposal_id)
+            .ok_or(VoteError::ProposalNotFound)?;
+
+        let depends_on = lock(proposal).depends_on.clone();
+        let accepted = accepted_ids(&proposals, &depends_on);
+        let mut proposal = lock(proposal);
+        proposal.check_open(&accepted)?;
+        proposal.try_add_vote(vote)
+    }
+
+    /// Evaluates all proposals to determine their current status.
+    ///
+    /// Dependencies are handled as in [`Engine::evaluate_all`]: a proposal
+    /// whose dependencies were not all accepted before this call keeps its
+    /// status, and its window is paused until they are.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp for evaluation
+    pub fn evaluate_all(&self, now: DateTime<Utc>) {
+        let proposals = self
+            .proposals
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        let ids: Vec<Uuid> = proposals.keys().copied().collect();
+        let accepted = accepted_ids(&proposals, &ids);
+        for proposal in proposals.values() {
+            let mut proposal = lock(proposal);
+            let blocked = !proposal.dependencies_met(&accepted);
+            proposal.hold_for_dependencies(blocked, now);
+            if !blocked {
+                proposal.evaluate(now);
+            }
+        }
+    }
+
+    /// Returns a copy of a specific proposal.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to retrieve
+    ///
+    /// # Returns
+    /// `Some(Proposal)` if found, `None` otherwise
+    pub fn get_proposal(&self, proposal_id: Uuid) -> Option<Proposal> {
+        let proposals = self
+            .proposals
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        proposals.get(&proposal_id).map(|p| lock(p).clone())
+    }
+
+    /// Converts back into a single-threaded [`Engine`].
+    ///
+    /// Proposals are ordered by creation time, oldest first.
+    pub fn into_engine(self) -> Engine {
+        let mut engine = Engine::new();
+        let mut proposals: Vec<Proposal> = self
+            .proposals
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+            .into_values()
+            .map(|p| p.into_inner().unwrap_or_else(PoisonError::into_inner))
+            .collect();
+        proposals.sort_by_key(|p| (p.created_at, p.id));
+        for proposal in proposals {
+            engine.add_proposal(proposal);
+        }
+        engine
+    }
+}
+
+impl From<Engine> for ConcurrentEngine {
+    fn from(engine: Engine) -> Self {
+        let concurrent = Self::new();
+        for proposal in engine.proposals {
+            concurrent.add_proposal(proposal);
+        }
+        concurrent
+    }
+}
+
+/// Returns which of `ids` name accepted proposals, locking each in turn.
+fn accepted_ids(proposals: &HashMap<Uuid, Mutex<Proposal>>, ids: &[Uuid]) -> HashSet<Uuid> {
+    ids.iter()
+        .filter(|id| {
+            proposals
+                .get(id)
+                .is_some_and(|p| lock(p).status == ProposalStatus::Accepted)
+        })
+        .copied()
+        .collect()
+}
+
+/// Locks a proposal, recovering the data if another thread panicked while holding it.
+fn lock(proposal: &Mutex<Proposal>) -> MutexGuard<'_, Proposal> {
+    proposal.lock().unwrap_or_else(PoisonError::into_inner)
+}
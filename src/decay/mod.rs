@@ -3,15 +3,29 @@
 //! Implements different models for how vote weights decrease over time,
 //! encouraging early participation in the voting process.
 
+use alloc::vec::Vec;
+use core::fmt;
+
 use serde::{Deserialize, Serialize};
 
+use crate::math;
+
+/// Smallest exponent used by [`DecayModel::Polynomial`] when computing weights.
+///
+/// Non-positive exponents are rejected by [`DecayModel::validate`], but
+/// `weight_calc` clamps to this value so an unvalidated model can never
+/// produce weights above 1.0.
+pub const MIN_POLYNOMIAL_EXPONENT: f64 = 1e-6;
+
 /// Models for how vote weights decay over time.
 ///
 /// Each model provides a different curve for weight reduction:
 /// - Linear: Steady decline from 1.0 to 0.1
 /// - Exponential: Rapid early decline, slower later
 /// - Stepped: Discrete weight levels based on voting phases
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// - SteppedN: Discrete weight levels over any number of equal phases
+/// - Polynomial: Generalised linear decay with a tunable curve
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum DecayModel {
     /// Linear decay from 1.0 to 0.1 over the voting period
     Linear,
@@ -19,11 +33,82 @@ pub enum DecayModel {
     Exponential(f64),
     /// Stepped decay with discrete weight levels (1.0, 0.5, 0.1)
     Stepped,
+    /// Stepped decay over `n` equal phases with weights evenly spaced from 1.0 to 0.1
+    ///
+    /// Phase `i` (from 0) carries weight `1.0 - i * 0.9 / (n - 1)`. Phase
+    /// boundaries match [`DecayModel::Stepped`] for `n = 3`, though the middle
+    /// weight is 0.55 rather than 0.5. Fewer than two phases are rejected by
+    /// [`DecayModel::validate`] and treated as two by `weight_calc`.
+    SteppedN(usize),
+    /// Polynomial decay `1 - (t/total)^exponent` with configurable exponent
+    ///
+    /// An exponent above 1.0 decays slowly then fast, below 1.0 decays fast
+    /// then slow, and exactly 1.0 is equivalent to [`DecayModel::Linear`].
+    Polynomial(f64),
+}
+
+/// Errors produced when validating a [`DecayModel`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecayError {
+    /// Polynomial exponent must be strictly positive
+    NonPositiveExponent(f64),
+    /// Stepped decay needs at least two phases
+    TooFewSteps(usize),
+}
+
+impl fmt::Display for DecayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecayError::NonPositiveExponent(e) => {
+                write!(f, "polynomial decay exponent must be positive, got {e}")
+            }
+            DecayError::TooFewSteps(n) => {
+                write!(f, "stepped decay needs at least 2 phases, got {n}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecayError {}
+
+impl DecayModel {
+    /// Checks that the model parameters describe a sensible decay curve.
+    ///
+    /// # Returns
+    /// `Ok(())` if the model is valid, or the first problem found
+    pub fn validate(&self) -> Result<(), DecayError> {
+        match self {
+            DecayModel::Polynomial(exponent) if exponent.is_nan() || *exponent <= 0.0 => {
+                Err(DecayError::NonPositiveExponent(*exponent))
+            }
+            DecayModel::SteppedN(n) if *n < 2 => Err(DecayError::TooFewSteps(*n)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Samples the weight curve at evenly spaced times across `[0, total]`.
+    ///
+    /// # Arguments
+    /// * `total` - Total voting period duration (seconds)
+    /// * `points` - Number of samples; values below 2 yield just the endpoints
+    ///
+    /// # Returns
+    /// `(t, weight)` pairs from [`weight_calc`], starting at 0 and ending at `total`
+    pub fn sample(&self, total: u64, points: usize) -> Vec<(u64, f64)> {
+        let points = points.max(2);
+        (0..points)
+            .map(|i| {
+                let t = (total as u128 * i as u128 / (points - 1) as u128) as u64;
+                (t, weight_calc(self, t, total))
+            })
+            .collect()
+    }
 }
 
 /// Calculates the weight multiplier for a vote based on the decay model and timing.
 ///
 /// All models enforce a minimum weight of 0.1 to ensure every vote has some influence.
+/// A zero-length period has no time to decay over, so every vote gets full weight.
 ///
 /// # Arguments
 /// * `model` - The decay model to use
@@ -46,13 +131,16 @@ pub enum DecayModel {
 /// assert!((weight - 1.0).abs() < 0.01);
 /// ```
 pub fn weight_calc(model: &DecayModel, t: u64, total: u64) -> f64 {
+    if total == 0 {
+        return 1.0;
+    }
     match model {
         DecayModel::Linear => {
             let w = 1.0 - (t as f64 / total as f64);
             w.max(0.1)
         }
         DecayModel::Exponential(rate) => {
-            let w = (-rate * t as f64).exp();
+            let w = math::exp(-rate * t as f64);
             w.max(0.1)
         }
         DecayModel::Stepped => {
@@ -64,5 +152,17 @@ pub fn weight_calc(model: &DecayModel, t: u64, total: u64) -> f64 {
                 0.1
             }
         }
+        DecayModel::SteppedN(n) => {
+            let n = (*n).max(2) as u128;
+            // Phase i covers t up to floor((i + 1) * total / n), inclusive.
+            let phase = ((t as u128 * n).saturating_sub(1) / total as u128).min(n - 1);
+            let w = 1.0 - phase as f64 * 0.9 / (n - 1) as f64;
+            w.max(0.1)
+        }
+        DecayModel::Polynomial(exponent) => {
+            let progress = (t as f64 / total as f64).min(1.0);
+            let w = 1.0 - math::powf(progress, exponent.max(MIN_POLYNOMIAL_EXPONENT));
+            w.max(0.1)
+        }
     }
 }
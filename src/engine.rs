@@ -3,11 +3,39 @@
 //! The main coordinator for the Verdyce consensus system. Manages proposals,
 //! votes, and evaluation logic.
 
-use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::models::proposal::{Proposal, ProposalStatus};
-use crate::models::vote::Vote;
+use crate::models::vote::{Vote, VoteError};
+use crate::storage::ProposalStore;
+use crate::window::{ExtensionPolicy, WindowState};
+
+/// Pending proposals with at most this many seconds left, grace period
+/// included, are reported as nearing expiry by [`Engine::summary`].
+pub const NEAR_EXPIRY_SECONDS: u64 = 300;
+
+/// Aggregate health metrics for an engine, as returned by [`Engine::summary`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EngineSummary {
+    /// Number of proposals in each status, including zero counts
+    pub counts_by_status: HashMap<ProposalStatus, usize>,
+    /// Pending proposals within [`NEAR_EXPIRY_SECONDS`] of expiring
+    pub nearing_expiry: usize,
+    /// Mean approval ratio across pending proposals, or 0.0 if there are none
+    pub average_pending_ratio: f64,
+    /// Number of votes stored across all proposals
+    pub total_votes: usize,
+}
+
+/// Callback invoked with a proposal, its previous status and its new status.
+///
+/// Listeners must be `Send` so an engine can move between threads, e.g. into
+/// a spawned task.
+pub type StatusListener = Box<dyn FnMut(&Proposal, ProposalStatus, ProposalStatus) + Send>;
 
 /// The main consensus engine that coordinates proposals and voting.
 ///
@@ -16,9 +44,26 @@ use crate::models::vote::Vote;
 /// - Cast votes on proposals
 /// - Evaluate proposal outcomes
 /// - Extend voting windows when appropriate
+/// - Persist proposals to an optional [`ProposalStore`]
+///
+/// Serializing an engine captures its proposals; the store and status
+/// listeners are not serialized.
+#[derive(Serialize, Deserialize)]
 pub struct Engine {
     /// Collection of all proposals managed by this engine
     pub proposals: Vec<Proposal>,
+    /// Backend that proposals are persisted to, if any
+    #[serde(skip)]
+    store: Option<Box<dyn ProposalStore + Send>>,
+    /// Callbacks notified whenever a proposal changes status
+    #[serde(skip)]
+    listeners: Vec<StatusListener>,
+    /// Extension parameters applied on every [`Engine::tick`]
+    #[serde(default)]
+    extension_policy: Option<ExtensionPolicy>,
+    /// Seconds a vote's timestamp may differ from its arrival in [`Engine::cast_vote_at`]
+    #[serde(default)]
+    max_clock_skew: u64,
 }
 
 impl Default for Engine {
@@ -31,9 +76,74 @@ impl Engine {
     pub fn new() -> Self {
         Self {
             proposals: Vec::new(),
+            store: None,
+            listeners: Vec::new(),
+            extension_policy: None,
+            max_clock_skew: 0,
+        }
+    }
+
+    /// Creates an engine that persists proposals to the given store.
+    ///
+    /// Proposals already in the store are loaded into the engine.
+    ///
+    /// # Arguments
+    /// * `store` - Backend to load from and persist to
+    pub fn with_store(store: Box<dyn ProposalStore + Send>) -> Self {
+        Self {
+            proposals: store.load_all(),
+            store: Some(store),
+            listeners: Vec::new(),
+            extension_policy: None,
+            max_clock_skew: 0,
         }
     }
 
+    /// Returns the backing store, if one is configured.
+    pub fn store(&self) -> Option<&(dyn ProposalStore + Send)> {
+        self.store.as_deref()
+    }
+
+    /// Saves every proposal to the backing store.
+    ///
+    /// Does nothing if the engine has no store.
+    pub fn persist_all(&mut self) {
+        if let Some(store) = self.store.as_mut() {
+            for proposal in &self.proposals {
+                store.save(proposal);
+            }
+        }
+    }
+
+    /// Registers a callback fired whenever a proposal changes status.
+    ///
+    /// The callback receives the proposal after the change, followed by the
+    /// old and new status. It is invoked from [`Engine::evaluate_all`].
+    ///
+    /// # Arguments
+    /// * `listener` - Callback to register
+    pub fn on_status_change(&mut self, listener: StatusListener) {
+        self.listeners.push(listener);
+    }
+
+    /// Serializes the engine state to JSON.
+    ///
+    /// # Returns
+    /// A JSON snapshot of every proposal, or the serialization error
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Restores an engine from a JSON snapshot produced by [`Engine::to_json`].
+    ///
+    /// The restored engine has no backing store.
+    ///
+    /// # Arguments
+    /// * `json` - Snapshot to restore
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
     /// Adds a new proposal to the engine.
     ///
     /// # Arguments
@@ -50,34 +160,168 @@ impl Engine {
     ///
     /// # Returns
     /// `true` if the vote was successfully cast, `false` if the proposal
-    /// doesn't exist or is not in pending status
+    /// doesn't exist, is not in pending status, is waiting on dependencies,
+    /// the vote is dated outside the window, the validator is not eligible,
+    /// or the vote repeats one already recorded
     pub fn cast_vote(&mut self, proposal_id: Uuid, vote: Vote) -> bool {
-        if let Some(proposal) = self.proposals.iter_mut().find(|p| p.id == proposal_id)
-            && proposal.status == ProposalStatus::Pending
-        {
-            proposal.add_vote(vote);
-            return true;
+        self.try_cast_vote(proposal_id, vote).is_ok()
+    }
+
+    /// Attempts to cast a vote on a proposal, reporting why it was refused.
+    ///
+    /// Votes on a proposal whose dependencies have not all been accepted are
+    /// refused with [`VoteError::DependenciesPending`]. The vote is recorded
+    /// with [`Proposal::try_add_vote`], so one dated before the window opened
+    /// or after its grace period is refused with [`VoteError::BeforeWindowStart`]
+    /// or [`VoteError::AfterGracePeriod`].
+    ///
+    /// The vote's timestamp is trusted otherwise. When it is chosen by the
+    /// client, use [`Engine::cast_vote_at`] to hold it to the time the vote
+    /// was received.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to vote on
+    /// * `vote` - The vote to cast
+    ///
+    /// # Returns
+    /// `Ok(())` if the vote was cast, otherwise the reason it was refused
+    pub fn try_cast_vote(&mut self, proposal_id: Uuid, vote: Vote) -> Result<(), VoteError> {
+        self.open_for_voting(proposal_id)?.try_add_vote(vote)
+    }
+
+    /// Sets how far a vote's timestamp may differ from `now` in [`Engine::cast_vote_at`].
+    ///
+    /// Tolerates small clock differences between clients and the server.
+    /// Defaults to zero, so no future-dated votes are accepted and every
+    /// vote is weighted as of its arrival.
+    ///
+    /// # Arguments
+    /// * `seconds` - Allowed skew in seconds
+    pub fn set_max_clock_skew(&mut self, seconds: u64) {
+        self.max_clock_skew = seconds;
+    }
+
+    /// Returns the allowed clock skew in seconds.
+    pub fn max_clock_skew(&self) -> u64 {
+        self.max_clock_skew
+    }
+
+    /// Casts a vote after checking the voting window at the vote's timestamp.
+    ///
+    /// Unlike [`Engine::cast_vote`], this refuses votes received or dated
+    /// before the window opened or after its grace period, even on a
+    /// proposal that is still pending because it hasn't been evaluated yet.
+    ///
+    /// Vote weight decays with the vote's timestamp, so the timestamp is
+    /// held to within the allowed clock skew of `now`. Votes dated further
+    /// ahead are refused with [`VoteError::FutureTimestamp`], since they
+    /// only cost the voter weight and usually indicate a broken client
+    /// clock. Votes dated further back are moved forward to `now` minus
+    /// the skew, so a late vote can't claim the weight of an early one by
+    /// backdating. Accepted votes are recorded with
+    /// [`Proposal::try_add_vote_at`], so votes arriving during the grace
+    /// period are flagged.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to vote on
+    /// * `vote` - The vote to cast
+    /// * `now` - When the vote was received
+    ///
+    /// # Returns
+    /// `Ok(())` if the vote was cast, otherwise the reason it was refused
+    pub fn cast_vote_at(
+        &mut self,
+        proposal_id: Uuid,
+        mut vote: Vote,
+        now: DateTime<Utc>,
+    ) -> Result<(), VoteError> {
+        let skew = Duration::seconds(self.max_clock_skew as i64);
+        let proposal = self.open_for_voting(proposal_id)?;
+
+        for at in [now, vote.timestamp] {
+            match proposal.voting_window.state(at) {
+                WindowState::NotStarted => return Err(VoteError::BeforeWindowStart),
+                WindowState::Expired => return Err(VoteError::AfterGracePeriod),
+                _ => {}
+            }
+        }
+        if vote.timestamp > now + skew {
+            return Err(VoteError::FutureTimestamp);
         }
-        false
+        vote.timestamp = vote.timestamp.max(now - skew);
+        proposal.try_add_vote_at(vote, now)
+    }
+
+    /// Finds a pending proposal whose dependencies have all been accepted.
+    fn open_for_voting(&mut self, proposal_id: Uuid) -> Result<&mut Proposal, VoteError> {
+        let accepted = self.accepted_ids();
+        let proposal = self
+            .proposals
+            .iter_mut()
+            .find(|p| p.id == proposal_id)
+            .ok_or(VoteError::ProposalNotFound)?;
+        proposal.check_open(&accepted)?;
+        Ok(proposal)
     }
 
     /// Evaluates all proposals to determine their current status.
     ///
     /// This checks each proposal against its threshold and time constraints
     /// to determine if it should be accepted, rejected, or expired.
+    /// Proposals whose dependencies were not all accepted before this call
+    /// are skipped and keep their status. Their voting window is paused from
+    /// `now` (or its start, if later) and resumes on the first call after
+    /// every dependency is accepted, so time spent blocked doesn't count
+    /// against it. A window already paused for another reason is left alone.
+    /// Registered status listeners are notified of every transition.
     ///
     /// # Arguments
     /// * `now` - Current timestamp for evaluation
     pub fn evaluate_all(&mut self, now: DateTime<Utc>) {
+        let accepted = self.accepted_ids();
         for proposal in &mut self.proposals {
+            let blocked = !proposal.dependencies_met(&accepted);
+            proposal.hold_for_dependencies(blocked, now);
+            if blocked {
+                continue;
+            }
+            let previous = proposal.status.clone();
             proposal.evaluate(now);
+            if proposal.status != previous {
+                for listener in &mut self.listeners {
+                    listener(proposal, previous.clone(), proposal.status.clone());
+                }
+            }
         }
     }
 
+    /// Returns the pending proposals whose dependencies have all been accepted.
+    ///
+    /// Proposals without dependencies are always activatable while pending.
+    /// A dependency on a proposal the engine doesn't hold is never satisfied.
+    pub fn activatable(&self) -> Vec<Uuid> {
+        let accepted = self.accepted_ids();
+        self.proposals
+            .iter()
+            .filter(|p| p.status == ProposalStatus::Pending)
+            .filter(|p| p.dependencies_met(&accepted))
+            .map(|p| p.id)
+            .collect()
+    }
+
+    fn accepted_ids(&self) -> HashSet<Uuid> {
+        self.proposals
+            .iter()
+            .filter(|p| p.status == ProposalStatus::Accepted)
+            .map(|p| p.id)
+            .collect()
+    }
+
     /// Attempts to extend voting windows for all proposals that meet extension criteria.
     ///
     /// A proposal's window may be extended if it's both near the approval threshold
-    /// and near the time expiry.
+    /// and near the time expiry. Proposals with their own `extension_policy`
+    /// use it instead of the parameters given here.
     ///
     /// # Arguments
     /// * `now` - Current timestamp
@@ -91,9 +335,63 @@ impl Engine {
         threshold_proximity: f64,
         time_proximity: f64,
     ) {
+        self.extend_all_with(
+            now,
+            ExtensionPolicy {
+                extension_seconds,
+                threshold_proximity,
+                time_proximity,
+                max_extensions: None,
+            },
+        );
+    }
+
+    /// Extends every proposal by its own policy, falling back to `default`.
+    fn extend_all_with(&mut self, now: DateTime<Utc>, default: ExtensionPolicy) {
         for proposal in &mut self.proposals {
-            proposal.extend_window(now, extension_seconds, threshold_proximity, time_proximity);
+            let policy = proposal.extension_policy.unwrap_or(default);
+            proposal.extend_with_policy(now, policy);
+        }
+    }
+
+    /// Sets the extension parameters used by [`Engine::tick`].
+    ///
+    /// # Arguments
+    /// * `policy` - Extension parameters, or `None` to stop extending on tick
+    pub fn set_extension_policy(&mut self, policy: Option<ExtensionPolicy>) {
+        self.extension_policy = policy;
+    }
+
+    /// Returns the extension parameters used by [`Engine::tick`], if any.
+    pub fn extension_policy(&self) -> Option<ExtensionPolicy> {
+        self.extension_policy
+    }
+
+    /// Advances the engine to `now`.
+    ///
+    /// Runs [`Engine::evaluate_all`] and then, if an extension policy is set,
+    /// extends proposals as [`Engine::maybe_extend_all`] would, honouring the
+    /// policy's `max_extensions`.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp
+    ///
+    /// # Returns
+    /// IDs of the proposals whose status changed during this tick
+    pub fn tick(&mut self, now: DateTime<Utc>) -> Vec<Uuid> {
+        let before: Vec<ProposalStatus> = self.proposals.iter().map(|p| p.status.clone()).collect();
+
+        self.evaluate_all(now);
+        if let Some(policy) = self.extension_policy {
+            self.extend_all_with(now, policy);
         }
+
+        self.proposals
+            .iter()
+            .zip(before)
+            .filter(|(p, status)| p.status != *status)
+            .map(|(p, _)| p.id)
+            .collect()
     }
 
     /// Returns all proposals that are currently pending (accepting votes).
@@ -108,15 +406,125 @@ impl Engine {
     pub fn get_finalized(&self) -> Vec<&Proposal> {
         self.proposals
             .iter()
-            .filter(|p| {
-                matches!(
-                    p.status,
-                    ProposalStatus::Accepted | ProposalStatus::Rejected | ProposalStatus::Expired
-                )
-            })
+            .filter(|p| p.status.is_final())
+            .collect()
+    }
+
+    /// Returns all proposals with the given status.
+    ///
+    /// # Arguments
+    /// * `status` - Status to filter by
+    pub fn get_by_status(&self, status: ProposalStatus) -> Vec<&Proposal> {
+        self.proposals
+            .iter()
+            .filter(|p| p.status == status)
             .collect()
     }
 
+    /// Counts proposals in each status.
+    ///
+    /// # Returns
+    /// A map containing every status, with zero for statuses no proposal is in
+    pub fn count_by_status(&self) -> HashMap<ProposalStatus, usize> {
+        let mut counts: HashMap<ProposalStatus, usize> =
+            ProposalStatus::ALL.into_iter().map(|s| (s, 0)).collect();
+        for proposal in &self.proposals {
+            *counts.entry(proposal.status.clone()).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Summarises the engine's proposals at `now`.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp, used to find proposals nearing expiry
+    ///
+    /// # Returns
+    /// Status counts, expiry warnings, average pending ratio and vote total
+    pub fn summary(&self, now: DateTime<Utc>) -> EngineSummary {
+        let pending = self.get_by_status(ProposalStatus::Pending);
+        let nearing_expiry = pending
+            .iter()
+            .filter(|p| p.voting_window.remaining(now) <= NEAR_EXPIRY_SECONDS)
+            .count();
+        let average_pending_ratio = if pending.is_empty() {
+            0.0
+        } else {
+            pending
+                .iter()
+                .map(|p| p.current_approval_ratio())
+                .sum::<f64>()
+                / pending.len() as f64
+        };
+
+        EngineSummary {
+            counts_by_status: self.count_by_status(),
+            nearing_expiry,
+            average_pending_ratio,
+            total_votes: self.proposals.iter().map(|p| p.votes().len()).sum(),
+        }
+    }
+
+    /// Returns all proposals carrying the given tag, ignoring case.
+    ///
+    /// # Arguments
+    /// * `tag` - Tag to filter by
+    pub fn get_proposals_by_tag(&self, tag: &str) -> Vec<&Proposal> {
+        self.proposals.iter().filter(|p| p.has_tag(tag)).collect()
+    }
+
+    /// Returns all proposals matching an arbitrary predicate, in insertion order.
+    ///
+    /// # Arguments
+    /// * `pred` - Returns `true` for proposals to include
+    ///
+    /// # Examples
+    /// ```
+    /// use verdyce_core::engine::Engine;
+    /// use verdyce_core::models::proposal::ProposalStatus;
+    ///
+    /// let engine = Engine::new();
+    /// let busy = engine.find(|p| p.status == ProposalStatus::Pending && p.votes().len() > 10);
+    /// assert!(busy.is_empty());
+    /// ```
+    pub fn find(&self, pred: impl Fn(&Proposal) -> bool) -> Vec<&Proposal> {
+        self.proposals.iter().filter(|p| pred(p)).collect()
+    }
+
+    /// Returns the first proposal matching a predicate, in insertion order.
+    ///
+    /// # Arguments
+    /// * `pred` - Returns `true` for the proposal to return
+    pub fn find_one(&self, pred: impl Fn(&Proposal) -> bool) -> Option<&Proposal> {
+        self.proposals.iter().find(|p| pred(p))
+    }
+
+    /// Removes a proposal from the engine.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to remove
+    ///
+    /// # Returns
+    /// The removed proposal, or `None` if it wasn't found
+    pub fn remove_proposal(&mut self, proposal_id: Uuid) -> Option<Proposal> {
+        let index = self.proposals.iter().position(|p| p.id == proposal_id)?;
+        Some(self.proposals.remove(index))
+    }
+
+    /// Moves all finalized proposals out of the engine.
+    ///
+    /// Pending proposals stay in place in their original order.
+    ///
+    /// # Returns
+    /// The archived proposals that had reached a final state
+    pub fn archive_finalized(&mut self) -> Vec<Proposal> {
+        let (finalized, pending) = std::mem::take(&mut self.proposals)
+            .into_iter()
+            .partition(|p| p.status.is_final());
+        self.proposals = pending;
+        finalized
+    }
+
     /// Retrieves a specific proposal by ID.
     ///
     /// # Arguments
@@ -127,4 +535,73 @@ impl Engine {
     pub fn get_proposal(&self, proposal_id: Uuid) -> Option<&Proposal> {
         self.proposals.iter().find(|p| p.id == proposal_id)
     }
+
+    /// Applies a change to a single proposal and then runs the engine's hooks.
+    ///
+    /// Once `change` returns, the proposal's cached vote weights are
+    /// discarded, status listeners are notified if its status changed, and
+    /// it is saved to the backing store if one is configured. Votes can
+    /// only be changed through the proposal's own methods, so eligibility
+    /// and deduplication still apply. Prefer [`Engine::cast_vote_at`],
+    /// [`Engine::extend`] and [`Engine::cancel`] where they fit.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to change
+    /// * `change` - Edits the proposal
+    ///
+    /// # Returns
+    /// What `change` returned, or `None` if the proposal wasn't found
+    pub fn update<R>(
+        &mut self,
+        proposal_id: Uuid,
+        change: impl FnOnce(&mut Proposal) -> R,
+    ) -> Option<R> {
+        let proposal = self.proposals.iter_mut().find(|p| p.id == proposal_id)?;
+        let previous = proposal.status.clone();
+        let result = change(proposal);
+
+        proposal.invalidate_weight_cache();
+        if proposal.status != previous {
+            for listener in &mut self.listeners {
+                listener(proposal, previous.clone(), proposal.status.clone());
+            }
+        }
+        if let Some(store) = self.store.as_mut() {
+            store.save(proposal);
+        }
+        Some(result)
+    }
+
+    /// Extends the voting window of a pending proposal unconditionally.
+    ///
+    /// The window's `max_extensions` still applies.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to extend
+    /// * `seconds` - Number of seconds to add to the voting period
+    ///
+    /// # Returns
+    /// `true` if the window was extended, `false` if the proposal doesn't
+    /// exist, is not pending, or has used up its extensions
+    pub fn extend(&mut self, proposal_id: Uuid, seconds: u64) -> bool {
+        self.update(proposal_id, |p| {
+            p.status == ProposalStatus::Pending && p.voting_window.extend(seconds)
+        })
+        .unwrap_or(false)
+    }
+
+    /// Withdraws a pending proposal.
+    ///
+    /// Status listeners are notified of the cancellation.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to cancel
+    ///
+    /// # Returns
+    /// `true` if the proposal was cancelled, `false` if it doesn't exist or
+    /// was not pending
+    #[cfg(feature = "clock")]
+    pub fn cancel(&mut self, proposal_id: Uuid) -> bool {
+        self.update(proposal_id, |p| p.cancel()).unwrap_or(false)
+    }
 }
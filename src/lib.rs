@@ -10,9 +10,13 @@
 //! - **Multiple decay models**: Linear, exponential, and stepped decay functions
 //! - **Flexible thresholds**: Linear, exponential, and sigmoid threshold progression
 //!
+//! Without the default `std` feature the crate is `no_std` and provides only
+//! the [`decay`] and [`threshold`] math.
+//!
 //! ## Usage
 //!
 //! ```rust
+//! # #[cfg(feature = "clock")] {
 //! use verdyce_core::engine::Engine;
 //! use verdyce_core::models::proposal::Proposal;
 //! use verdyce_core::decay::DecayModel;
@@ -27,10 +31,27 @@
 //!     ThresholdModel::Linear(0.1, 0.5)
 //! );
 //! engine.add_proposal(proposal);
+//! # }
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod async_engine;
+#[cfg(feature = "std")]
+pub mod concurrent;
 pub mod decay;
+#[cfg(feature = "std")]
 pub mod engine;
+mod math;
+#[cfg(feature = "std")]
 pub mod models;
+#[cfg(feature = "std")]
+pub mod storage;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod threshold;
+#[cfg(feature = "std")]
 pub mod window;
@@ -0,0 +1,24 @@
+//! Floating-point functions that work with and without `std`.
+//!
+//! `f64::exp` and friends live in `std`; without it the decay and threshold
+//! math falls back to `libm`.
+
+#[cfg(feature = "std")]
+pub(crate) fn exp(x: f64) -> f64 {
+    x.exp()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn exp(x: f64) -> f64 {
+    libm::exp(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+    x.powf(n)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn powf(x: f64, n: f64) -> f64 {
+    libm::pow(x, n)
+}
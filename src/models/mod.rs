@@ -3,4 +3,5 @@
 //! Core data structures for proposals and votes in the Verdyce consensus system.
 
 pub mod proposal;
+pub mod template;
 pub mod vote;
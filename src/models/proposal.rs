@@ -2,28 +2,436 @@
 //!
 //! Defines the core proposal structure and evaluation logic for the consensus system.
 
-use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::decay::DecayModel;
-use crate::models::vote::{Vote, VoteChoice, calculate_vote_weight};
-use crate::threshold::{ThresholdModel, threshold_calc};
-use crate::window::VotingWindow;
+use crate::decay::{DecayError, DecayModel, weight_calc};
+use crate::models::vote::{
+    AbstainMode, RevisionPenalty, Vote, VoteChoice, VoteCommitment, VoteError, VoteWeightMode,
+    calculate_vote_weight_at_elapsed, commitment_hash,
+};
+use crate::threshold::{ThresholdError, ThresholdModel, threshold_calc_with_participation};
+use crate::window::{
+    DEFAULT_GRACE_PERIOD, ExtensionPolicy, VelocityPolicy, VotingWindow, WindowState,
+};
+
+/// Tolerance used when comparing the approval ratio against the threshold.
+///
+/// Absorbs floating-point rounding, so a ratio of `0.7` still clears a
+/// threshold that works out to `0.7000000000000001`.
+pub const DEFAULT_THRESHOLD_EPSILON: f64 = 1e-9;
 
 /// Represents the current status of a proposal in the consensus system.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+///
+/// Serializes, displays and parses as a stable lowercase string such as
+/// `"pending"` or `"no_quorum"`. The capitalised names written by earlier versions are still
+/// accepted when deserializing.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
 pub enum ProposalStatus {
     /// Proposal is actively accepting votes
+    #[serde(alias = "Pending")]
     Pending,
     /// Proposal has met the approval threshold and is accepted
+    #[serde(alias = "Accepted")]
     Accepted,
     /// Proposal failed to meet the threshold within the time limit
+    #[serde(alias = "Rejected")]
     Rejected,
     /// Proposal exceeded the grace period without resolution
+    #[serde(alias = "Expired")]
+    Expired,
+    /// Proposal was withdrawn before it resolved
+    #[serde(alias = "Cancelled")]
+    Cancelled,
+    /// Voting ended without enough participation to decide either way
+    #[serde(alias = "NoQuorum")]
+    NoQuorum,
+}
+
+impl ProposalStatus {
+    /// Every status a proposal can be in.
+    pub const ALL: [ProposalStatus; 6] = [
+        ProposalStatus::Pending,
+        ProposalStatus::Accepted,
+        ProposalStatus::Rejected,
+        ProposalStatus::Expired,
+        ProposalStatus::Cancelled,
+        ProposalStatus::NoQuorum,
+    ];
+
+    /// Returns `true` if the proposal has reached a final state.
+    pub fn is_final(&self) -> bool {
+        !matches!(self, ProposalStatus::Pending)
+    }
+
+    /// Returns the stable lowercase name of the status, e.g. `"pending"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProposalStatus::Pending => "pending",
+            ProposalStatus::Accepted => "accepted",
+            ProposalStatus::Rejected => "rejected",
+            ProposalStatus::Expired => "expired",
+            ProposalStatus::Cancelled => "cancelled",
+            ProposalStatus::NoQuorum => "no_quorum",
+        }
+    }
+}
+
+impl fmt::Display for ProposalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when a string does not name a [`ProposalStatus`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseStatusError(pub String);
+
+impl fmt::Display for ParseStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown proposal status: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseStatusError {}
+
+impl FromStr for ProposalStatus {
+    type Err = ParseStatusError;
+
+    /// Parses a status name, ignoring case and surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        ProposalStatus::ALL
+            .into_iter()
+            .find(|status| status.as_str().eq_ignore_ascii_case(name))
+            .ok_or_else(|| ParseStatusError(s.to_string()))
+    }
+}
+
+/// Why a proposal failed to pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RejectionReason {
+    /// The approval ratio stayed below the threshold, or can no longer reach it
+    ThresholdNotMet,
+    /// Too few validators or too little weight took part by the end of the window
+    QuorumNotMet,
+    /// A validator holding veto power vetoed the proposal
+    Vetoed,
+    /// The grace period ended before the proposal was evaluated
     Expired,
 }
 
+/// Errors produced when a proposal's configuration is invalid.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProposalError {
+    /// The decay model parameters are invalid
+    Decay(DecayError),
+    /// The threshold model parameters are invalid
+    Threshold(ThresholdError),
+    /// The minimum threshold lies outside `[0, 1]`
+    MinThresholdOutOfRange(f64),
+    /// The voting period has zero length
+    ZeroDuration,
+}
+
+impl fmt::Display for ProposalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProposalError::Decay(e) => write!(f, "invalid decay model: {e}"),
+            ProposalError::Threshold(e) => write!(f, "invalid threshold model: {e}"),
+            ProposalError::MinThresholdOutOfRange(v) => {
+                write!(f, "minimum threshold must be within [0, 1], got {v}")
+            }
+            ProposalError::ZeroDuration => write!(f, "voting duration must be non-zero"),
+        }
+    }
+}
+
+impl std::error::Error for ProposalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ProposalError::Decay(e) => Some(e),
+            ProposalError::Threshold(e) => Some(e),
+            ProposalError::MinThresholdOutOfRange(_) | ProposalError::ZeroDuration => None,
+        }
+    }
+}
+
+impl From<DecayError> for ProposalError {
+    fn from(e: DecayError) -> Self {
+        ProposalError::Decay(e)
+    }
+}
+
+impl From<ThresholdError> for ProposalError {
+    fn from(e: ThresholdError) -> Self {
+        ProposalError::Threshold(e)
+    }
+}
+
+/// Errors produced by [`ProposalBuilder::build`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildError {
+    /// A required builder field was never set
+    MissingField(&'static str),
+    /// The assembled proposal failed validation
+    Invalid(ProposalError),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::MissingField(field) => write!(f, "missing required field `{field}`"),
+            BuildError::Invalid(e) => write!(f, "invalid proposal: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BuildError::MissingField(_) => None,
+            BuildError::Invalid(e) => Some(e),
+        }
+    }
+}
+
+impl From<ProposalError> for BuildError {
+    fn from(e: ProposalError) -> Self {
+        BuildError::Invalid(e)
+    }
+}
+
+/// Detailed result of evaluating a proposal at a point in time.
+///
+/// Captures the inputs behind the status decision so callers can explain
+/// outcomes such as "rejected because threshold 0.72 > ratio 0.61".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvaluationOutcome {
+    /// Status of the proposal after evaluation
+    pub status: ProposalStatus,
+    /// Approval threshold in effect at evaluation time
+    pub threshold: f64,
+    /// Weighted approval ratio at evaluation time
+    pub approval_ratio: f64,
+    /// Seconds elapsed since voting started
+    pub elapsed: u64,
+    /// Number of distinct validators that have voted
+    pub participants: usize,
+    /// Why the proposal failed, if it was rejected or expired
+    pub rejection_reason: Option<RejectionReason>,
+}
+
+/// Evaluates a proposal at `now` without modifying it.
+///
+/// Reports the status [`Proposal::evaluate`] would move the proposal to,
+/// along with the threshold and ratio behind that decision. It never reads
+/// the system clock and tolerates degenerate proposals, such as ones with
+/// no votes or a zero-length window, so it is safe to call from sandboxed
+/// or WASM hosts.
+///
+/// # Arguments
+/// * `proposal` - The proposal to evaluate
+/// * `now` - Time to evaluate at
+///
+/// # Returns
+/// The outcome the proposal would have at `now`
+pub fn evaluate_pure(proposal: &Proposal, now: DateTime<Utc>) -> EvaluationOutcome {
+    proposal.outcome_at(now)
+}
+
+/// A recorded change of a proposal's status.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusTransition {
+    /// Status before the change
+    pub from: ProposalStatus,
+    /// Status after the change
+    pub to: ProposalStatus,
+    /// When the change was made
+    pub at: DateTime<Utc>,
+    /// Weighted approval ratio at the time of the change
+    pub approval_ratio: f64,
+    /// Approval threshold in effect at the time of the change
+    pub threshold: f64,
+}
+
+/// A recorded change to a proposal's description.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Amendment {
+    /// When the amendment was made
+    pub at: DateTime<Utc>,
+    /// Description before the amendment
+    pub previous_description: String,
+    /// Whether votes cast before the amendment were discarded
+    pub votes_invalidated: bool,
+}
+
+/// Frozen view of a proposal's weights and outcome at one instant.
+///
+/// Vote weights and thresholds depend on when they are computed; a snapshot
+/// pins them down so audit logs can be reproduced later.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProposalSnapshot {
+    /// Instant the snapshot describes
+    pub taken_at: DateTime<Utc>,
+    /// Status of the proposal at that instant
+    pub status: ProposalStatus,
+    /// Weighted approval ratio at that instant
+    pub approval_ratio: f64,
+    /// Approval threshold in effect at that instant
+    pub threshold: f64,
+    /// Seconds elapsed since voting started
+    pub elapsed: u64,
+    /// Effective weight of each direct vote, keyed by validator
+    pub vote_weights: Vec<(Uuid, f64)>,
+}
+
+/// Result of importing a batch of votes with [`Proposal::add_votes_bulk`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BulkVoteReport {
+    /// Number of votes recorded
+    pub accepted: usize,
+    /// Number of votes refused
+    pub rejected: usize,
+    /// Position in the batch, validator and reason for each refused vote
+    pub rejections: Vec<(usize, Uuid, VoteError)>,
+}
+
+/// Per-choice breakdown of the weight behind a proposal's approval ratio.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeightedTally {
+    /// Total effective weight of Yes votes
+    pub yes_weight: f64,
+    /// Total effective weight of No votes
+    pub no_weight: f64,
+    /// Number of abstaining validators
+    pub abstain_count: usize,
+    /// Total effective weight of Abstain votes, decayed like any other vote.
+    /// Reported as abstention strength; it only affects the ratio under
+    /// [`AbstainMode::CountInDenominator`]
+    pub abstain_weight: f64,
+    /// Number of validators whose vote, direct or delegated, was counted
+    pub total_validators: usize,
+    /// `yes_weight` over the weight counted by the proposal's [`AbstainMode`],
+    /// or 0.0 when that weight is zero
+    pub approval_ratio: f64,
+}
+
+/// Decayed vote weights remembered between evaluations.
+///
+/// Holds the weight of each direct vote, by index into `votes`, before stake
+/// influence is applied, along with running Yes, No and Abstain totals of the
+/// stake-weighted votes. The entries are only valid for the window start,
+/// duration and pauses, decay model, revision penalty and weight mode they
+/// were computed with.
+#[derive(Debug, Default)]
+struct WeightCache(Mutex<Option<CachedWeights>>);
+
+#[derive(Debug, Clone)]
+struct CachedWeights {
+    window: VotingWindow,
+    decay_model: DecayModel,
+    revision_penalty: RevisionPenalty,
+    weight_mode: VoteWeightMode,
+    weights: Vec<f64>,
+    yes_weight: f64,
+    no_weight: f64,
+    abstain_weight: f64,
+}
+
+impl CachedWeights {
+    fn empty_for(proposal: &Proposal) -> Self {
+        Self {
+            window: proposal.voting_window.clone(),
+            decay_model: proposal.decay_model.clone(),
+            revision_penalty: proposal.revision_penalty,
+            weight_mode: proposal.weight_mode,
+            weights: Vec::with_capacity(proposal.votes.len()),
+            yes_weight: 0.0,
+            no_weight: 0.0,
+            abstain_weight: 0.0,
+        }
+    }
+
+    /// Returns the decayed, revision-penalised weight of a vote.
+    ///
+    /// Decay follows the voting clock, so time the window spent paused
+    /// before the vote was cast doesn't count against it.
+    fn weigh(&self, vote: &Vote) -> f64 {
+        calculate_vote_weight_at_elapsed(
+            vote,
+            self.window.elapsed(vote.timestamp),
+            self.window.total_duration(),
+            &self.decay_model,
+            self.revision_penalty,
+        )
+    }
+
+    /// Adds (or with a negative `sign`, removes) a vote's stake-weighted share of the totals.
+    fn tally(&mut self, vote: &Vote, weight: f64, sign: f64) {
+        let counted = sign * weight * self.weight_mode.influence(vote.stake);
+        match vote.choice {
+            VoteChoice::Yes => self.yes_weight += counted,
+            VoteChoice::No => self.no_weight += counted,
+            VoteChoice::Abstain => self.abstain_weight += counted,
+            VoteChoice::Veto => {}
+        }
+    }
+
+    /// Weighs a vote appended to `votes` and adds it to the totals.
+    fn push(&mut self, vote: &Vote) {
+        let weight = self.weigh(vote);
+        self.weights.push(weight);
+        self.tally(vote, weight, 1.0);
+    }
+
+    /// Swaps the vote at `index` for its replacement in the totals.
+    fn replace(&mut self, index: usize, old: &Vote, new: &Vote) {
+        let weight = self.weigh(new);
+        self.tally(old, self.weights[index], -1.0);
+        self.tally(new, weight, 1.0);
+        self.weights[index] = weight;
+    }
+
+    fn is_valid_for(&self, proposal: &Proposal) -> bool {
+        let window = &proposal.voting_window;
+        self.window.start_time == window.start_time
+            && self.window.total_duration() == window.total_duration()
+            && self.window.paused_total == window.paused_total
+            && self.window.paused_at == window.paused_at
+            && self.decay_model == proposal.decay_model
+            && self.revision_penalty == proposal.revision_penalty
+            && self.weight_mode == proposal.weight_mode
+            && self.weights.len() <= proposal.votes.len()
+    }
+}
+
+impl WeightCache {
+    fn lock(&self) -> MutexGuard<'_, Option<CachedWeights>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get_mut(&mut self) -> Option<&mut CachedWeights> {
+        self.0
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+    }
+}
+
+impl Clone for WeightCache {
+    fn clone(&self) -> Self {
+        WeightCache(Mutex::new(self.lock().clone()))
+    }
+}
+
 /// A proposal in the consensus system with associated voting logic.
 ///
 /// Each proposal contains all the information needed to manage its lifecycle:
@@ -40,8 +448,8 @@ pub struct Proposal {
     pub description: String,
     /// When this proposal was created
     pub created_at: DateTime<Utc>,
-    /// All votes cast on this proposal
-    pub votes: Vec<Vote>,
+    /// Current vote of each validator, see [`Proposal::votes`]
+    votes: Vec<Vote>,
     /// Current status of the proposal
     pub status: ProposalStatus,
     /// Voting window configuration and state
@@ -50,17 +458,107 @@ pub struct Proposal {
     pub decay_model: DecayModel,
     /// Model for how approval thresholds change over time
     pub threshold_model: ThresholdModel,
+    /// Number of validators eligible to vote, used to compute participation
+    #[serde(default)]
+    pub max_validators: Option<usize>,
+    /// Minimum number of distinct validators that must vote before acceptance
+    #[serde(default)]
+    pub quorum: Option<usize>,
+    /// Minimum total Yes and No weight that must be cast before acceptance
+    #[serde(default)]
+    pub weight_quorum: Option<f64>,
+    /// Delegated voting power, mapping each delegator to their chosen delegate
+    #[serde(default)]
+    pub delegations: HashMap<Uuid, Uuid>,
+    /// How strongly vote changes reduce a vote's weight
+    #[serde(default)]
+    pub revision_penalty: RevisionPenalty,
+    /// Validators whose veto votes block the proposal
+    #[serde(default)]
+    pub vetoers: HashSet<Uuid>,
+    /// Floor applied to the time-based threshold, e.g. 0.667 for a supermajority
+    #[serde(default)]
+    pub min_threshold: f64,
+    /// How far below the threshold the ratio may fall and still count as meeting it
+    ///
+    /// Defaults to [`DEFAULT_THRESHOLD_EPSILON`]; zero makes the comparison strict.
+    #[serde(default = "default_threshold_epsilon")]
+    pub threshold_epsilon: f64,
+    /// How each vote's stake is turned into influence
+    #[serde(default)]
+    pub weight_mode: VoteWeightMode,
+    /// Largest stake a single vote may carry (`None` = uncapped)
+    ///
+    /// Votes above the cap are refused. Early rejection uses it to bound
+    /// what validators who haven't voted yet could still add.
+    #[serde(default)]
+    pub max_stake: Option<u64>,
+    /// Whether abstentions lower the approval ratio
+    #[serde(default)]
+    pub abstain_mode: AbstainMode,
+    /// Free-form labels such as "treasury" or "protocol" used for filtering
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Validators allowed to vote; an empty set means voting is open to anyone
+    #[serde(default)]
+    pub validators: HashSet<Uuid>,
+    /// Every status change this proposal has gone through, oldest first
+    #[serde(default)]
+    pub history: Vec<StatusTransition>,
+    /// Every amendment to the description, oldest first
+    #[serde(default)]
+    pub amendments: Vec<Amendment>,
+    /// Why the proposal failed, set when evaluation rejects or expires it
+    #[serde(default)]
+    pub rejection_reason: Option<RejectionReason>,
+    /// Proposals that must be accepted before this one is evaluated
+    #[serde(default)]
+    pub depends_on: Vec<Uuid>,
+    /// Whether the engine paused the voting window until `depends_on` is accepted
+    #[serde(default)]
+    pub dependency_hold: bool,
+    /// Sealed votes awaiting reveal, keyed by validator
+    #[serde(default)]
+    pub commitments: HashMap<Uuid, VoteCommitment>,
+    /// Votes that were later replaced, per validator, oldest first
+    #[serde(default)]
+    pub replaced_votes: HashMap<Uuid, Vec<Vote>>,
+    /// Extension rules applied by [`Proposal::maybe_extend`]
+    #[serde(default)]
+    pub extension_policy: Option<ExtensionPolicy>,
+    /// Whether evaluation ignores votes dated after the main window closed,
+    /// see [`Proposal::tally_at_window_close`]
+    #[serde(default)]
+    pub freeze_tally_at_close: bool,
+    /// Whether [`Proposal::evaluate`] stores a snapshot in `last_snapshot`
+    #[serde(default)]
+    pub record_snapshots: bool,
+    /// Snapshot taken by the most recent evaluation, if recording is enabled
+    #[serde(default)]
+    pub last_snapshot: Option<ProposalSnapshot>,
+    /// Decayed weights of `votes`, reused until the window or votes change
+    #[serde(skip)]
+    weight_cache: WeightCache,
+}
+
+fn default_threshold_epsilon() -> f64 {
+    DEFAULT_THRESHOLD_EPSILON
 }
 
 impl Proposal {
     /// Creates a new proposal with the specified parameters.
     ///
+    /// The grace period defaults to [`DEFAULT_GRACE_PERIOD`]; use
+    /// [`Proposal::builder`] to choose a different one. Voting starts now;
+    /// use [`Proposal::new_at`] to supply the time instead.
+    ///
     /// # Arguments
     /// * `title` - Human-readable title for the proposal
     /// * `description` - Detailed description of the proposal
     /// * `duration` - Voting period duration in seconds
     /// * `decay_model` - How vote weights should decay over time
     /// * `threshold_model` - How approval thresholds should change over time
+    #[cfg(feature = "clock")]
     pub fn new(
         title: String,
         description: String,
@@ -68,7 +566,35 @@ impl Proposal {
         decay_model: DecayModel,
         threshold_model: ThresholdModel,
     ) -> Self {
-        let now = Utc::now();
+        Self::new_at(
+            title,
+            description,
+            duration,
+            decay_model,
+            threshold_model,
+            Utc::now(),
+        )
+    }
+
+    /// Creates a new proposal whose voting starts at `now`.
+    ///
+    /// Behaves like [`Proposal::new`] without reading the system clock.
+    ///
+    /// # Arguments
+    /// * `title` - Human-readable title for the proposal
+    /// * `description` - Detailed description of the proposal
+    /// * `duration` - Voting period duration in seconds
+    /// * `decay_model` - How vote weights should decay over time
+    /// * `threshold_model` - How approval thresholds should change over time
+    /// * `now` - Creation time and start of the voting window
+    pub fn new_at(
+        title: String,
+        description: String,
+        duration: u64,
+        decay_model: DecayModel,
+        threshold_model: ThresholdModel,
+        now: DateTime<Utc>,
+    ) -> Self {
         Self {
             id: Uuid::new_v4(),
             title,
@@ -76,124 +602,1592 @@ impl Proposal {
             created_at: now,
             votes: Vec::new(),
             status: ProposalStatus::Pending,
-            voting_window: VotingWindow::new(now, duration, 30),
+            voting_window: VotingWindow::new(now, duration, DEFAULT_GRACE_PERIOD),
             decay_model,
             threshold_model,
+            max_validators: None,
+            quorum: None,
+            weight_quorum: None,
+            delegations: HashMap::new(),
+            revision_penalty: RevisionPenalty::default(),
+            vetoers: HashSet::new(),
+            min_threshold: 0.0,
+            threshold_epsilon: DEFAULT_THRESHOLD_EPSILON,
+            weight_mode: VoteWeightMode::default(),
+            max_stake: None,
+            abstain_mode: AbstainMode::default(),
+            tags: Vec::new(),
+            validators: HashSet::new(),
+            history: Vec::new(),
+            amendments: Vec::new(),
+            rejection_reason: None,
+            depends_on: Vec::new(),
+            dependency_hold: false,
+            commitments: HashMap::new(),
+            replaced_votes: HashMap::new(),
+            extension_policy: None,
+            freeze_tally_at_close: false,
+            record_snapshots: false,
+            last_snapshot: None,
+            weight_cache: WeightCache::default(),
         }
     }
 
-    /// Adds a vote to this proposal.
+    /// Sets the minimum number of distinct validators required for acceptance.
+    ///
+    /// Abstaining validators count toward the quorum.
     ///
     /// # Arguments
-    /// * `vote` - The vote to add
-    pub fn add_vote(&mut self, vote: Vote) {
-        self.votes.push(vote);
+    /// * `quorum` - Minimum number of distinct validators
+    pub fn with_quorum(mut self, quorum: usize) -> Self {
+        self.quorum = Some(quorum);
+        self
     }
 
-    /// Evaluates the current state of the proposal and updates its status.
+    /// Sets the minimum total decayed weight required for acceptance.
     ///
-    /// This method checks:
-    /// - If the proposal has expired (past grace period)
-    /// - If the proposal has met the approval threshold (accepted)
-    /// - If the voting period has ended without meeting threshold (rejected)
+    /// Unlike [`Proposal::with_quorum`] this measures weight rather than
+    /// headcount, so late votes contribute less. Abstentions don't count.
     ///
     /// # Arguments
-    /// * `now` - Current timestamp for evaluation
-    pub fn evaluate(&mut self, now: DateTime<Utc>) {
-        if self.status != ProposalStatus::Pending {
-            return;
-        }
+    /// * `weight_quorum` - Minimum combined effective weight of Yes and No votes
+    pub fn with_weight_quorum(mut self, weight_quorum: f64) -> Self {
+        self.weight_quorum = Some(weight_quorum);
+        self
+    }
 
-        let elapsed = self.voting_window.elapsed(now);
-        let total = self.voting_window.total_duration();
-        let grace_cutoff = total + self.voting_window.grace_period;
+    /// Sets how abstentions affect the approval ratio.
+    ///
+    /// # Arguments
+    /// * `abstain_mode` - Whether abstain weight joins the ratio's denominator
+    pub fn with_abstain_mode(mut self, abstain_mode: AbstainMode) -> Self {
+        self.abstain_mode = abstain_mode;
+        self
+    }
 
-        if elapsed >= grace_cutoff {
-            self.status = ProposalStatus::Expired;
-            return;
-        }
+    /// Caps the stake a single vote may carry.
+    ///
+    /// Needed for early rejection to stay sound once stakes other than 1
+    /// are in play, see [`Proposal::evaluate`].
+    ///
+    /// # Arguments
+    /// * `max_stake` - Largest stake accepted on a vote
+    pub fn with_max_stake(mut self, max_stake: u64) -> Self {
+        self.max_stake = Some(max_stake);
+        self
+    }
 
-        let threshold = threshold_calc(&self.threshold_model, elapsed, total);
-        let approval_ratio = self.current_approval_ratio();
+    /// Adds a proposal that must be accepted before this one is evaluated.
+    ///
+    /// Dependencies are enforced by [`Engine`](crate::engine::Engine) and
+    /// [`ConcurrentEngine`](crate::concurrent::ConcurrentEngine), which
+    /// refuse votes and pause the window until they are all accepted.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the prerequisite proposal
+    pub fn with_dependency(mut self, proposal_id: Uuid) -> Self {
+        self.depends_on.push(proposal_id);
+        self
+    }
 
-        if elapsed < total && approval_ratio >= threshold {
-            self.status = ProposalStatus::Accepted;
-        } else if elapsed >= total {
-            self.status = ProposalStatus::Rejected;
-        }
+    /// Sets the extension rules used by [`Proposal::maybe_extend`].
+    ///
+    /// # Arguments
+    /// * `policy` - When and by how much to extend this proposal's window
+    pub fn with_extension_policy(mut self, policy: ExtensionPolicy) -> Self {
+        self.extension_policy = Some(policy);
+        self
     }
 
-    /// Attempts to extend the voting window if conditions are met.
+    /// Makes evaluation use the tally frozen at the main window's close.
     ///
-    /// Extension occurs when the proposal is both near the approval threshold
-    /// and near the time expiry, allowing for last-minute consensus building.
+    /// Votes cast during the grace period are still recorded but no longer
+    /// affect the approval ratio.
     ///
     /// # Arguments
-    /// * `now` - Current timestamp
-    /// * `extension_seconds` - How many seconds to extend by
-    /// * `threshold_proximity` - Ratio (0.0-1.0) of threshold that triggers extension
-    /// * `time_proximity` - Ratio (0.0-1.0) of time elapsed that triggers extension
-    pub fn extend_window(
-        &mut self,
-        now: DateTime<Utc>,
-        extension_seconds: u64,
-        threshold_proximity: f64,
-        time_proximity: f64,
-    ) {
-        if self.status != ProposalStatus::Pending {
-            return;
-        }
+    /// * `enabled` - Whether evaluation uses [`Proposal::tally_at_window_close`]
+    pub fn with_frozen_tally(mut self, enabled: bool) -> Self {
+        self.freeze_tally_at_close = enabled;
+        self
+    }
 
-        let elapsed = self.voting_window.elapsed(now);
-        let total = self.voting_window.total_duration();
-        let threshold = threshold_calc(&self.threshold_model, elapsed, total);
-        let approval_ratio = self.current_approval_ratio();
+    /// Enables or disables storing a snapshot on every evaluation.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether evaluation should update `last_snapshot`
+    pub fn with_snapshots(mut self, enabled: bool) -> Self {
+        self.record_snapshots = enabled;
+        self
+    }
 
-        let near_threshold = approval_ratio >= threshold * threshold_proximity;
-        let near_expiry = elapsed as f64 >= total as f64 * time_proximity;
+    /// Sets a floor that the threshold curve can raise but never lower.
+    ///
+    /// The floor is applied after the model's own clamp, so it may exceed
+    /// the default 0.9 ceiling. Checked by [`Proposal::validate`].
+    ///
+    /// # Arguments
+    /// * `min_threshold` - Lowest approval threshold allowed, within `[0, 1]`
+    pub fn with_min_threshold(mut self, min_threshold: f64) -> Self {
+        self.min_threshold = min_threshold;
+        self
+    }
+
+    /// Sets the tolerance for a ratio that falls just short of the threshold.
+    ///
+    /// # Arguments
+    /// * `epsilon` - Allowed shortfall; 0.0 requires `ratio >= threshold` exactly
+    pub fn with_threshold_epsilon(mut self, epsilon: f64) -> Self {
+        self.threshold_epsilon = epsilon;
+        self
+    }
+
+    /// Sets the tags used to categorise this proposal.
+    ///
+    /// # Arguments
+    /// * `tags` - Labels such as "treasury" or "protocol"
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Returns `true` if the proposal carries `tag`, ignoring case.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// Creates a new proposal, rejecting a zero duration or invalid decay or threshold models.
+    ///
+    /// Takes the same arguments as [`Proposal::new`].
+    ///
+    /// # Returns
+    /// The proposal, or the first configuration problem found
+    #[cfg(feature = "clock")]
+    pub fn try_new(
+        title: String,
+        description: String,
+        duration: u64,
+        decay_model: DecayModel,
+        threshold_model: ThresholdModel,
+    ) -> Result<Self, ProposalError> {
+        let proposal = Self::new(title, description, duration, decay_model, threshold_model);
+        proposal.validate()?;
+        Ok(proposal)
+    }
 
-        if near_threshold && near_expiry {
-            self.voting_window.extend(extension_seconds);
+    /// Checks that this proposal's configuration is valid.
+    pub fn validate(&self) -> Result<(), ProposalError> {
+        if self.voting_window.duration == 0 {
+            return Err(ProposalError::ZeroDuration);
+        }
+        self.decay_model.validate()?;
+        self.threshold_model.validate()?;
+        if !(0.0..=1.0).contains(&self.min_threshold) {
+            return Err(ProposalError::MinThresholdOutOfRange(self.min_threshold));
         }
+        Ok(())
     }
 
-    /// Calculates the current approval ratio based on weighted votes.
+    /// Adds a vote to this proposal.
     ///
-    /// The approval ratio is calculated as:
-    /// `yes_weight / (yes_weight + no_weight)`
+    /// Each validator has at most one effective vote. A new vote from a
+    /// validator who already voted replaces their current one, and the
+    /// replaced vote is kept in `replaced_votes`. The stored `revision` is
+    /// the incoming one if it is higher, otherwise one past the replaced
+    /// vote's. A retried submission, matching a vote already recorded for
+    /// the validator in [`Vote::dedup_key`], choice and timestamp, is
+    /// ignored. Votes from validators outside a non-empty registry, or with
+    /// a stake above `max_stake`, are ignored.
     ///
-    /// Abstain votes are not counted in the ratio calculation.
+    /// # Arguments
+    /// * `vote` - The vote to add
     ///
     /// # Returns
-    /// The approval ratio as a value between 0.0 and 1.0, or 0.0 if no votes
-    pub fn current_approval_ratio(&self) -> f64 {
-        let mut yes_weight = 0.0;
-        let mut total_weight = 0.0;
+    /// `true` if the vote was newly recorded, `false` if it was a duplicate,
+    /// the validator is not eligible or the stake is over the cap
+    pub fn add_vote(&mut self, vote: Vote) -> bool {
+        self.record_vote(vote).is_ok()
+    }
 
-        for vote in &self.votes {
-            let weight = calculate_vote_weight(
-                vote,
-                self.voting_window.start_time,
-                self.voting_window.total_duration(),
-                &self.decay_model,
-            );
+    /// Stores a vote, reporting why it was refused.
+    fn record_vote(&mut self, mut vote: Vote) -> Result<(), VoteError> {
+        if !self.is_eligible(&vote.validator_id) {
+            return Err(VoteError::NotEligible);
+        }
+        if self.max_stake.is_some_and(|max| vote.stake > max) {
+            return Err(VoteError::StakeTooHigh);
+        }
+        if self.is_resubmission(&vote) {
+            return Err(VoteError::Duplicate);
+        }
 
-            match vote.choice {
-                VoteChoice::Yes => {
-                    yes_weight += weight;
-                    total_weight += weight;
-                }
-                VoteChoice::No => {
-                    total_weight += weight;
-                }
-                VoteChoice::Abstain => {}
+        // A cache left over from an earlier window or model can't be patched
+        let cache_current = self.weight_cache_is_current();
+        if !cache_current {
+            self.invalidate_weight_cache();
+        }
+        if let Some(index) = self
+            .votes
+            .iter()
+            .position(|v| v.validator_id == vote.validator_id)
+        {
+            vote.revision = vote.revision.max(self.votes[index].revision + 1);
+            if cache_current
+                && let Some(cached) = self.weight_cache.get_mut()
+                && index < cached.weights.len()
+            {
+                cached.replace(index, &self.votes[index], &vote);
             }
+            let replaced = std::mem::replace(&mut self.votes[index], vote);
+            self.replaced_votes
+                .entry(replaced.validator_id)
+                .or_default()
+                .push(replaced);
+        } else {
+            if cache_current
+                && let Some(cached) = self.weight_cache.get_mut()
+                && cached.weights.len() == self.votes.len()
+            {
+                cached.push(&vote);
+            }
+            self.votes.push(vote);
         }
+        Ok(())
+    }
 
-        if total_weight > 0.0 {
-            yes_weight / total_weight
-        } else {
-            0.0
+    /// Returns `true` if `vote` repeats a vote already recorded for its validator.
+    ///
+    /// Stored revisions may have been bumped past the submitted one, so a
+    /// recorded vote matches when it has the same choice and timestamp and
+    /// a revision at least as high as the incoming vote's.
+    fn is_resubmission(&self, vote: &Vote) -> bool {
+        let replaced = self
+            .replaced_votes
+            .get(&vote.validator_id)
+            .into_iter()
+            .flatten();
+        self.votes
+            .iter()
+            .filter(|v| v.validator_id == vote.validator_id)
+            .chain(replaced)
+            .any(|v| {
+                v.choice == vote.choice
+                    && v.timestamp == vote.timestamp
+                    && v.revision >= vote.revision
+            })
+    }
+
+    /// Returns `true` if the weight cache was computed for the current window and models.
+    ///
+    /// Lets vote insertion update the running totals in place rather than
+    /// leaving them to be recomputed on the next evaluation.
+    fn weight_cache_is_current(&self) -> bool {
+        self.weight_cache
+            .lock()
+            .as_ref()
+            .is_some_and(|cached| cached.is_valid_for(self))
+    }
+
+    /// Adds a vote after checking that its timestamp falls inside the voting window.
+    ///
+    /// Vote weight is computed from the vote's own timestamp, so a vote dated
+    /// before the window opened would otherwise count at full weight. Votes
+    /// dated after the grace period are likewise refused. The window is
+    /// checked with [`VotingWindow::state`], so time spent paused doesn't
+    /// count toward the deadline. Accepted votes are stored as with
+    /// [`Proposal::add_vote`].
+    ///
+    /// # Arguments
+    /// * `vote` - The vote to add
+    ///
+    /// # Returns
+    /// `Ok(())` if the vote was recorded, or why it was refused
+    pub fn try_add_vote(&mut self, vote: Vote) -> Result<(), VoteError> {
+        match self.voting_window.state(vote.timestamp) {
+            WindowState::NotStarted => return Err(VoteError::BeforeWindowStart),
+            WindowState::Expired => return Err(VoteError::AfterGracePeriod),
+            _ => {}
         }
+        self.record_vote(vote)
+    }
+
+    /// Adds a vote received at `now` after checking its timestamp.
+    ///
+    /// Sets the vote's `cast_during_grace` flag like [`Proposal::add_vote_at`]
+    /// and then behaves like [`Proposal::try_add_vote`].
+    ///
+    /// # Arguments
+    /// * `vote` - The vote to add
+    /// * `now` - When the vote was received
+    ///
+    /// # Returns
+    /// `Ok(())` if the vote was recorded, or why it was refused
+    pub fn try_add_vote_at(&mut self, mut vote: Vote, now: DateTime<Utc>) -> Result<(), VoteError> {
+        vote.cast_during_grace = self.voting_window.state(now) == WindowState::GracePeriod;
+        self.try_add_vote(vote)
+    }
+
+    /// Imports a batch of votes, reporting which were refused and why.
+    ///
+    /// Each vote goes through [`Proposal::try_add_vote`] in order, so the
+    /// window bounds, eligibility and revision rules all apply, and a later
+    /// vote in the batch may replace an earlier one.
+    ///
+    /// # Arguments
+    /// * `votes` - Votes to import, oldest first
+    ///
+    /// # Returns
+    /// Counts of accepted and rejected votes along with each rejection reason
+    pub fn add_votes_bulk(&mut self, votes: Vec<Vote>) -> BulkVoteReport {
+        let mut report = BulkVoteReport::default();
+        for (index, vote) in votes.into_iter().enumerate() {
+            let validator_id = vote.validator_id;
+            match self.try_add_vote(vote) {
+                Ok(()) => report.accepted += 1,
+                Err(err) => {
+                    report.rejected += 1;
+                    report.rejections.push((index, validator_id, err));
+                }
+            }
+        }
+        report
+    }
+
+    /// Records a sealed vote to be revealed later with [`Proposal::reveal`].
+    ///
+    /// Committed votes don't count toward the approval ratio until revealed.
+    /// A new commitment from the same validator replaces the old one.
+    /// Commitments are only accepted while the window is open or extended,
+    /// and their stake is held to `max_stake` like any other vote.
+    ///
+    /// # Arguments
+    /// * `commitment` - The validator's sealed vote
+    /// * `now` - When the commitment was received
+    ///
+    /// # Returns
+    /// `Ok(())` if the commitment was recorded, or why it was refused
+    pub fn commit(
+        &mut self,
+        commitment: VoteCommitment,
+        now: DateTime<Utc>,
+    ) -> Result<(), VoteError> {
+        match self.voting_window.state(now) {
+            WindowState::Open | WindowState::Extended => {}
+            WindowState::NotStarted => return Err(VoteError::BeforeWindowStart),
+            WindowState::GracePeriod | WindowState::Expired => {
+                return Err(VoteError::CommitPhaseOver);
+            }
+        }
+        if !self.is_eligible(&commitment.validator_id) {
+            return Err(VoteError::NotEligible);
+        }
+        if self.max_stake.is_some_and(|max| commitment.stake > max) {
+            return Err(VoteError::StakeTooHigh);
+        }
+        self.commitments.insert(commitment.validator_id, commitment);
+        Ok(())
+    }
+
+    /// Opens a validator's commitment and adds the vote it sealed.
+    ///
+    /// Reveals are only accepted during the grace period, so no choice is
+    /// disclosed while others can still vote. The vote is timestamped at the
+    /// commitment time and carries the committed stake, so revealing late
+    /// doesn't cost decay weight. On a mismatch the commitment stays in place.
+    ///
+    /// # Arguments
+    /// * `validator_id` - UUID of the validator revealing
+    /// * `choice` - The choice that was committed to
+    /// * `nonce` - The secret the commitment was sealed with
+    /// * `now` - When the reveal was received
+    ///
+    /// # Returns
+    /// `Ok(())` if the vote was added, or why the reveal was refused
+    pub fn reveal(
+        &mut self,
+        validator_id: Uuid,
+        choice: VoteChoice,
+        nonce: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), VoteError> {
+        match self.voting_window.state(now) {
+            WindowState::GracePeriod => {}
+            WindowState::Expired => return Err(VoteError::AfterGracePeriod),
+            WindowState::NotStarted | WindowState::Open | WindowState::Extended => {
+                return Err(VoteError::RevealNotOpen);
+            }
+        }
+        let commitment = self
+            .commitments
+            .get(&validator_id)
+            .ok_or(VoteError::NoCommitment)?;
+        if commitment_hash(&choice, nonce) != commitment.commitment {
+            return Err(VoteError::CommitmentMismatch);
+        }
+
+        let (timestamp, stake) = (commitment.timestamp, commitment.stake);
+        self.commitments.remove(&validator_id);
+        let revision = self
+            .votes
+            .iter()
+            .find(|v| v.validator_id == validator_id)
+            .map_or(0, |v| v.revision + 1);
+        let vote = Vote {
+            validator_id,
+            choice,
+            timestamp,
+            revision,
+            reason: None,
+            stake,
+            cast_during_grace: true,
+            amended_after: false,
+        };
+        self.record_vote(vote)
+    }
+
+    /// Adds a vote received at `now`, recording whether it arrived during the grace period.
+    ///
+    /// Sets the vote's `cast_during_grace` flag from the window state at
+    /// `now` and then behaves like [`Proposal::add_vote`].
+    ///
+    /// # Arguments
+    /// * `vote` - The vote to add
+    /// * `now` - When the vote was received
+    ///
+    /// # Returns
+    /// `true` if the vote was recorded
+    pub fn add_vote_at(&mut self, mut vote: Vote, now: DateTime<Utc>) -> bool {
+        vote.cast_during_grace = self.voting_window.state(now) == WindowState::GracePeriod;
+        self.add_vote(vote)
+    }
+
+    /// Reports why the proposal can't take votes, if it can't.
+    ///
+    /// # Arguments
+    /// * `accepted` - IDs of the proposals that have been accepted
+    pub(crate) fn check_open(&self, accepted: &HashSet<Uuid>) -> Result<(), VoteError> {
+        if self.status != ProposalStatus::Pending {
+            return Err(VoteError::ProposalClosed);
+        }
+        if !self.dependencies_met(accepted) {
+            return Err(VoteError::DependenciesPending);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if every proposal in `depends_on` is in `accepted`.
+    pub(crate) fn dependencies_met(&self, accepted: &HashSet<Uuid>) -> bool {
+        self.depends_on.iter().all(|id| accepted.contains(id))
+    }
+
+    /// Stops or restarts the voting clock as dependencies block or unblock voting.
+    ///
+    /// A blocked window is paused from `now`, or its start if later, and
+    /// `dependency_hold` is set; a window already paused for another reason
+    /// is left alone. Once unblocked, a held window resumes at `now`.
+    ///
+    /// # Arguments
+    /// * `blocked` - Whether some dependency has not been accepted
+    /// * `now` - Current timestamp
+    pub(crate) fn hold_for_dependencies(&mut self, blocked: bool, now: DateTime<Utc>) {
+        if blocked {
+            if !self.dependency_hold && !self.voting_window.is_paused() {
+                let window = &mut self.voting_window;
+                window.pause(now.max(window.start_time));
+                self.dependency_hold = true;
+            }
+        } else if self.dependency_hold {
+            self.voting_window.resume(now);
+            self.dependency_hold = false;
+        }
+    }
+
+    /// Adds a validator to the registry of validators allowed to vote.
+    ///
+    /// While the registry is empty, anyone may vote.
+    ///
+    /// # Arguments
+    /// * `validator_id` - Validator to register
+    pub fn register_validator(&mut self, validator_id: Uuid) {
+        self.validators.insert(validator_id);
+    }
+
+    /// Returns `true` if the validator may vote on this proposal.
+    pub fn is_eligible(&self, validator_id: &Uuid) -> bool {
+        self.validators.is_empty() || self.validators.contains(validator_id)
+    }
+
+    /// Evaluates the current state of the proposal and updates its status.
+    ///
+    /// This method checks:
+    /// - If the proposal has expired (past grace period)
+    /// - If an authorized validator has vetoed the proposal (rejected)
+    /// - If the proposal has met the approval threshold, within
+    ///   `threshold_epsilon`, and quorum (accepted)
+    /// - If the voting period has ended without meeting quorum (no quorum)
+    /// - If the voting period has ended without meeting threshold (rejected)
+    /// - If the threshold can no longer be reached by the remaining validators (rejected)
+    ///
+    /// A proposal whose window has not started at `now` stays pending.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp for evaluation
+    pub fn evaluate(&mut self, now: DateTime<Utc>) {
+        self.evaluate_detailed(now);
+    }
+
+    /// Evaluates the proposal like [`Proposal::evaluate`] and reports why.
+    ///
+    /// Proposals that are no longer pending keep their status, but the
+    /// outcome still reports the current threshold and approval ratio.
+    /// Any status change is appended to [`Proposal::history`].
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp for evaluation
+    ///
+    /// # Returns
+    /// The resulting status along with the values used to decide it
+    pub fn evaluate_detailed(&mut self, now: DateTime<Utc>) -> EvaluationOutcome {
+        let outcome = self.outcome_at(now);
+        if outcome.status != self.status {
+            let previous = std::mem::replace(&mut self.status, outcome.status.clone());
+            self.rejection_reason = outcome.rejection_reason;
+            self.history.push(StatusTransition {
+                from: previous,
+                to: outcome.status.clone(),
+                at: now,
+                approval_ratio: outcome.approval_ratio,
+                threshold: outcome.threshold,
+            });
+        }
+        if self.record_snapshots {
+            self.last_snapshot = Some(self.snapshot(now));
+        }
+        outcome
+    }
+
+    /// Decides what status the proposal should have at `now` without changing it.
+    fn outcome_at(&self, now: DateTime<Utc>) -> EvaluationOutcome {
+        let elapsed = self.voting_window.elapsed(now);
+        let total = self.voting_window.total_duration();
+        let grace_cutoff = total + self.voting_window.grace_period;
+        let threshold = self.threshold_at(elapsed, total);
+        let approval_ratio = if self.freeze_tally_at_close {
+            self.tally_at_window_close()
+        } else {
+            self.current_approval_ratio()
+        };
+
+        let mut status = self.status.clone();
+        let mut rejection_reason = self.rejection_reason;
+        if status == ProposalStatus::Pending && now >= self.voting_window.start_time {
+            let quorum_met = self.quorum_met() && self.weight_quorum_met();
+            if elapsed >= grace_cutoff {
+                status = ProposalStatus::Expired;
+                rejection_reason = Some(RejectionReason::Expired);
+            } else if self.is_vetoed() {
+                status = ProposalStatus::Rejected;
+                rejection_reason = Some(RejectionReason::Vetoed);
+            } else if elapsed < total
+                && self.meets_threshold(approval_ratio, threshold)
+                && quorum_met
+            {
+                status = ProposalStatus::Accepted;
+            } else if elapsed >= total && !quorum_met {
+                status = ProposalStatus::NoQuorum;
+                rejection_reason = Some(RejectionReason::QuorumNotMet);
+            } else if elapsed >= total || self.threshold_unreachable(elapsed, total) {
+                status = ProposalStatus::Rejected;
+                rejection_reason = Some(RejectionReason::ThresholdNotMet);
+            }
+        }
+
+        EvaluationOutcome {
+            status,
+            threshold,
+            approval_ratio,
+            elapsed,
+            participants: self.voter_count(),
+            rejection_reason,
+        }
+    }
+
+    /// Withdraws a pending proposal.
+    ///
+    /// Cancelled proposals are final: evaluation leaves them untouched and
+    /// they no longer accept votes through the engine. The change is
+    /// appended to [`Proposal::history`].
+    ///
+    /// # Returns
+    /// `true` if the proposal was cancelled, `false` if it was not pending
+    #[cfg(feature = "clock")]
+    pub fn cancel(&mut self) -> bool {
+        self.cancel_at(Utc::now())
+    }
+
+    /// Withdraws a pending proposal, recording `now` as the time of cancellation.
+    ///
+    /// Behaves like [`Proposal::cancel`] without reading the system clock.
+    ///
+    /// # Arguments
+    /// * `now` - When the proposal was cancelled
+    ///
+    /// # Returns
+    /// `true` if the proposal was cancelled, `false` if it was not pending
+    pub fn cancel_at(&mut self, now: DateTime<Utc>) -> bool {
+        if self.status != ProposalStatus::Pending {
+            return false;
+        }
+
+        let elapsed = self.voting_window.elapsed(now);
+        let total = self.voting_window.total_duration();
+        self.history.push(StatusTransition {
+            from: ProposalStatus::Pending,
+            to: ProposalStatus::Cancelled,
+            at: now,
+            approval_ratio: self.current_approval_ratio(),
+            threshold: self.threshold_at(elapsed, total),
+        });
+        self.status = ProposalStatus::Cancelled;
+        true
+    }
+
+    /// Amends the proposal's description while voting is under way.
+    ///
+    /// A substantive amendment invalidates every vote cast so far, along
+    /// with their replaced revisions, unrevealed commitments and
+    /// delegations, so validators must decide again on the new text. For a
+    /// minor amendment the votes carry over and are marked `amended_after`.
+    /// Either way the change is recorded in `amendments`.
+    ///
+    /// # Arguments
+    /// * `new_description` - The amended description
+    /// * `invalidate_votes` - Whether to discard the votes cast before the amendment
+    /// * `now` - When the amendment is made
+    ///
+    /// # Returns
+    /// `true` if the proposal was amended, `false` if it is no longer pending
+    pub fn amend(
+        &mut self,
+        new_description: String,
+        invalidate_votes: bool,
+        now: DateTime<Utc>,
+    ) -> bool {
+        if self.status != ProposalStatus::Pending {
+            return false;
+        }
+
+        let previous_description = std::mem::replace(&mut self.description, new_description);
+        if invalidate_votes {
+            self.votes.clear();
+            self.replaced_votes.clear();
+            self.commitments.clear();
+            self.delegations.clear();
+            self.invalidate_weight_cache();
+        } else {
+            for vote in &mut self.votes {
+                vote.amended_after = true;
+            }
+        }
+        self.amendments.push(Amendment {
+            at: now,
+            previous_description,
+            votes_invalidated: invalidate_votes,
+        });
+        true
+    }
+
+    /// Creates a fresh copy of this proposal for a re-vote.
+    ///
+    /// The copy keeps the title, description, models and voting rules
+    /// (quorums, validators, vetoers, tags, dependencies and extension
+    /// limits) but gets a new ID and a new window of the same duration and
+    /// grace period starting at `now`. Votes, delegations, commitments,
+    /// amendment history and status are reset.
+    ///
+    /// # Arguments
+    /// * `now` - Creation time and start of the new voting window
+    pub fn fork(&self, now: DateTime<Utc>) -> Proposal {
+        let mut fork = self.clone();
+        fork.id = Uuid::new_v4();
+        fork.created_at = now;
+        fork.status = ProposalStatus::Pending;
+        fork.rejection_reason = None;
+        fork.voting_window = VotingWindow {
+            max_extensions: self.voting_window.max_extensions,
+            ..VotingWindow::new(
+                now,
+                self.voting_window.duration,
+                self.voting_window.grace_period,
+            )
+        };
+        fork.dependency_hold = false;
+        fork.votes.clear();
+        fork.delegations.clear();
+        fork.commitments.clear();
+        fork.replaced_votes.clear();
+        fork.history.clear();
+        fork.amendments.clear();
+        fork.last_snapshot = None;
+        fork.invalidate_weight_cache();
+        fork
+    }
+
+    /// Reopens a rejected, expired or inquorate proposal, e.g. after a governance override.
+    ///
+    /// The window is extended so that `now` falls back inside it with
+    /// `additional_duration` seconds of voting left. This counts as one of
+    /// the window's extensions, so reopening is refused once the window's
+    /// `max_extensions`, or that of the proposal's `extension_policy`, is
+    /// used up. A window paused while dependencies were pending is resumed
+    /// at `now`; the engine pauses it again on its next evaluation if they
+    /// still are. The reopening is recorded in `history` and any rejection
+    /// reason is cleared.
+    ///
+    /// # Arguments
+    /// * `additional_duration` - Seconds of voting to allow from `now`
+    /// * `now` - When the proposal is reopened
+    ///
+    /// # Returns
+    /// `true` if the proposal was reopened, `false` if it was not rejected,
+    /// expired or short of quorum, or has no extensions left
+    pub fn reopen(&mut self, additional_duration: u64, now: DateTime<Utc>) -> bool {
+        if !matches!(
+            self.status,
+            ProposalStatus::Rejected | ProposalStatus::Expired | ProposalStatus::NoQuorum
+        ) {
+            return false;
+        }
+        let policy_exhausted = self
+            .extension_policy
+            .and_then(|policy| policy.max_extensions)
+            .is_some_and(|max| self.voting_window.extensions >= max);
+        if policy_exhausted || !self.voting_window.can_extend() {
+            return false;
+        }
+
+        self.hold_for_dependencies(false, now);
+        let elapsed = self.voting_window.elapsed(now);
+        let total = self.voting_window.total_duration();
+        self.voting_window
+            .extend(elapsed.saturating_sub(total) + additional_duration);
+        self.invalidate_weight_cache();
+
+        let total = self.voting_window.total_duration();
+        let previous = std::mem::replace(&mut self.status, ProposalStatus::Pending);
+        self.rejection_reason = None;
+        self.history.push(StatusTransition {
+            from: previous,
+            to: ProposalStatus::Pending,
+            at: now,
+            approval_ratio: self.current_approval_ratio(),
+            threshold: self.threshold_at(elapsed, total),
+        });
+        true
+    }
+
+    /// Captures the proposal's ratio, threshold and vote weights at `now`.
+    ///
+    /// The status is taken as-is; call [`Proposal::evaluate`] first to bring
+    /// it up to date. Snapshots of an unchanged proposal at the same `now`
+    /// are identical.
+    ///
+    /// # Arguments
+    /// * `now` - Instant to compute the threshold at
+    pub fn snapshot(&self, now: DateTime<Utc>) -> ProposalSnapshot {
+        let elapsed = self.voting_window.elapsed(now);
+        let total = self.voting_window.total_duration();
+        let vote_weights = self
+            .effective_weights()
+            .into_iter()
+            .take(self.votes.len())
+            .map(|(vote, weight)| (vote.validator_id, weight))
+            .collect();
+
+        ProposalSnapshot {
+            taken_at: now,
+            status: self.status.clone(),
+            approval_ratio: self.current_approval_ratio(),
+            threshold: self.threshold_at(elapsed, total),
+            elapsed,
+            vote_weights,
+        }
+    }
+
+    /// Returns when voting closes, before the grace period.
+    ///
+    /// Reflects extensions and completed pauses of the voting window.
+    pub fn voting_deadline(&self) -> DateTime<Utc> {
+        self.voting_window.end_time()
+    }
+
+    /// Returns when the grace period ends and a pending proposal expires.
+    ///
+    /// Useful for scheduling a single evaluation instead of polling.
+    pub fn expiry_time(&self) -> DateTime<Utc> {
+        self.voting_deadline() + Duration::seconds(self.voting_window.grace_period as i64)
+    }
+
+    /// Returns `true` if no remaining validators could lift the ratio to the threshold.
+    ///
+    /// The best case assumes every validator that has not yet voted votes Yes
+    /// right now at the current decay weight with the largest stake allowed,
+    /// that existing votes stay as they are, and that participation becomes
+    /// full. Since decay weights only fall and thresholds only rise over time,
+    /// this is an upper bound on the ratio the proposal can ever reach.
+    ///
+    /// Without `max_validators` the number of future voters is unknown, and
+    /// the threshold is always considered reachable. The same goes for their
+    /// stake: without `max_stake` every future vote is assumed to carry a
+    /// stake of 1, so the check is skipped once any recorded vote carries a
+    /// different stake.
+    fn threshold_unreachable(&self, elapsed: u64, total: u64) -> bool {
+        let Some(max_validators) = self.max_validators else {
+            return false;
+        };
+        let max_stake = match self.max_stake {
+            Some(max_stake) => max_stake,
+            None if self.votes.iter().all(|v| v.stake == 1) => 1,
+            None => return false,
+        };
+
+        // Delegated power follows the delegate's decayed weight without stake
+        let per_validator = self.weight_mode.influence(max_stake).max(1.0);
+        let remaining = max_validators.saturating_sub(self.effective_participation());
+        let extra_yes =
+            remaining as f64 * weight_calc(&self.decay_model, elapsed, total) * per_validator;
+        let tally = self.weighted_tally();
+        let total_weight = self.ratio_denominator(&tally);
+        if total_weight + extra_yes <= 0.0 {
+            return false;
+        }
+
+        let best_ratio = (tally.yes_weight + extra_yes) / (total_weight + extra_yes);
+        let threshold =
+            threshold_calc_with_participation(&self.threshold_model, elapsed, total, 1.0)
+                .max(self.min_threshold);
+        !self.meets_threshold(best_ratio, threshold)
+    }
+
+    /// Attempts to extend the voting window if conditions are met.
+    ///
+    /// Extension occurs when the proposal is both near the approval threshold
+    /// and near the time expiry, allowing for last-minute consensus building.
+    /// No extension happens once the window's `max_extensions` is reached.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp
+    /// * `extension_seconds` - How many seconds to extend by
+    /// * `threshold_proximity` - Ratio (0.0-1.0) of threshold that triggers extension
+    /// * `time_proximity` - Ratio (0.0-1.0) of time elapsed that triggers extension
+    ///
+    /// # Returns
+    /// `true` if the window was extended
+    pub fn extend_window(
+        &mut self,
+        now: DateTime<Utc>,
+        extension_seconds: u64,
+        threshold_proximity: f64,
+        time_proximity: f64,
+    ) -> bool {
+        if self.status != ProposalStatus::Pending {
+            return false;
+        }
+
+        let elapsed = self.voting_window.elapsed(now);
+        let total = self.voting_window.total_duration();
+        let threshold = self.threshold_at(elapsed, total);
+        let approval_ratio = self.current_approval_ratio();
+
+        let near_threshold = approval_ratio >= threshold * threshold_proximity;
+        let near_expiry = elapsed as f64 >= total as f64 * time_proximity;
+
+        let extended =
+            near_threshold && near_expiry && self.voting_window.extend(extension_seconds);
+        if extended {
+            self.invalidate_weight_cache();
+        }
+        extended
+    }
+
+    /// Measures how quickly votes have been arriving.
+    ///
+    /// Counts the current votes timestamped within the last `window_secs`
+    /// seconds up to and including `now`.
+    ///
+    /// # Arguments
+    /// * `window_secs` - Length of the trailing interval in seconds
+    /// * `now` - End of the interval
+    ///
+    /// # Returns
+    /// Votes per second over the interval, or 0.0 if `window_secs` is zero
+    pub fn vote_velocity(&self, window_secs: u64, now: DateTime<Utc>) -> f64 {
+        if window_secs == 0 {
+            return 0.0;
+        }
+        let since = now - Duration::seconds(window_secs as i64);
+        let recent = self
+            .votes
+            .iter()
+            .filter(|v| v.timestamp > since && v.timestamp <= now)
+            .count();
+        recent as f64 / window_secs as f64
+    }
+
+    /// Extends the window if votes are still arriving quickly near expiry.
+    ///
+    /// Gives actively contested proposals more time regardless of how close
+    /// they are to the threshold. The window's `max_extensions` still applies.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp
+    /// * `policy` - Vote rate and timing that trigger the extension
+    ///
+    /// # Returns
+    /// `true` if the window was extended
+    pub fn extend_on_velocity(&mut self, now: DateTime<Utc>, policy: VelocityPolicy) -> bool {
+        if self.status != ProposalStatus::Pending {
+            return false;
+        }
+
+        let elapsed = self.voting_window.elapsed(now);
+        let total = self.voting_window.total_duration();
+        let near_expiry = elapsed as f64 >= total as f64 * policy.time_proximity;
+        let busy = self.vote_velocity(policy.window_seconds, now) >= policy.min_velocity;
+
+        let extended = near_expiry && busy && self.voting_window.extend(policy.extension_seconds);
+        if extended {
+            self.invalidate_weight_cache();
+        }
+        extended
+    }
+
+    /// Extends the window according to the proposal's own `extension_policy`.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp
+    ///
+    /// # Returns
+    /// `true` if the window was extended, `false` if it wasn't or no policy is set
+    pub fn maybe_extend(&mut self, now: DateTime<Utc>) -> bool {
+        match self.extension_policy {
+            Some(policy) => self.extend_with_policy(now, policy),
+            None => false,
+        }
+    }
+
+    /// Extends the window if the conditions of `policy` are met.
+    ///
+    /// Behaves like [`Proposal::extend_window`] with the policy's parameters,
+    /// and additionally refuses once the window has been extended
+    /// `policy.max_extensions` times.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp
+    /// * `policy` - Extension parameters to apply
+    ///
+    /// # Returns
+    /// `true` if the window was extended
+    pub fn extend_with_policy(&mut self, now: DateTime<Utc>, policy: ExtensionPolicy) -> bool {
+        if policy
+            .max_extensions
+            .is_some_and(|max| self.voting_window.extensions >= max)
+        {
+            return false;
+        }
+        self.extend_window(
+            now,
+            policy.extension_seconds,
+            policy.threshold_proximity,
+            policy.time_proximity,
+        )
+    }
+
+    /// Discards the cached decayed vote weights.
+    ///
+    /// Weights and the running approval totals are cached between
+    /// evaluations. Added and replaced votes update them in place; they are
+    /// recomputed from scratch when the window's start, duration or pauses,
+    /// the decay model, revision penalty or weight mode change. Calling this
+    /// is never needed for correctness, but frees the cached weights.
+    pub fn invalidate_weight_cache(&mut self) {
+        *self
+            .weight_cache
+            .0
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Delegates a validator's voting power to another validator.
+    ///
+    /// If the delegator never votes directly, their weight follows whatever
+    /// the delegate (or the delegate's own delegate, and so on) voted.
+    ///
+    /// Both validators must be eligible under the validator registry.
+    ///
+    /// # Arguments
+    /// * `delegator` - Validator handing over their voting power
+    /// * `delegate` - Validator receiving it
+    ///
+    /// # Returns
+    /// `Ok(())` if the delegation was recorded, or [`VoteError::NotEligible`]
+    /// if either validator is outside a non-empty registry
+    pub fn delegate(&mut self, delegator: Uuid, delegate: Uuid) -> Result<(), VoteError> {
+        if !self.is_eligible(&delegator) || !self.is_eligible(&delegate) {
+            return Err(VoteError::NotEligible);
+        }
+        self.delegations.insert(delegator, delegate);
+        Ok(())
+    }
+
+    /// Grants a validator the power to veto this proposal.
+    ///
+    /// # Arguments
+    /// * `validator_id` - Validator allowed to cast [`VoteChoice::Veto`]
+    pub fn grant_veto(&mut self, validator_id: Uuid) {
+        self.vetoers.insert(validator_id);
+    }
+
+    /// Returns `true` if any validator holding veto power has cast a veto.
+    ///
+    /// A veto blocks acceptance regardless of its timing or revision, so
+    /// unlike other votes it is not subject to decay. Vetoes from validators
+    /// without veto power are ignored entirely.
+    pub fn is_vetoed(&self) -> bool {
+        self.votes
+            .iter()
+            .any(|v| v.choice == VoteChoice::Veto && self.vetoers.contains(&v.validator_id))
+    }
+
+    /// Follows a delegation chain until it reaches a validator who voted directly.
+    ///
+    /// # Returns
+    /// The vote the delegator's power resolves to, or `None` if the chain
+    /// ends without a vote, loops back on itself or passes through a
+    /// validator outside the registry
+    fn resolve_delegation<'a>(
+        &self,
+        delegator: Uuid,
+        direct: &HashMap<Uuid, &'a Vote>,
+    ) -> Option<&'a Vote> {
+        if !self.is_eligible(&delegator) {
+            return None;
+        }
+        let mut visited = HashSet::from([delegator]);
+        let mut current = *self.delegations.get(&delegator)?;
+
+        loop {
+            if !self.is_eligible(&current) {
+                return None;
+            }
+            if let Some(vote) = direct.get(&current) {
+                return Some(vote);
+            }
+            if !visited.insert(current) {
+                return None;
+            }
+            current = *self.delegations.get(&current)?;
+        }
+    }
+
+    /// Returns the current vote of each validator who has voted, in the order they first voted.
+    ///
+    /// Votes can only be changed through [`Proposal::add_vote`] and its
+    /// siblings, which keep the cached weights in step.
+    pub fn votes(&self) -> &[Vote] {
+        &self.votes
+    }
+
+    /// Returns the number of distinct validators that have voted, including abstentions.
+    pub fn voter_count(&self) -> usize {
+        self.votes
+            .iter()
+            .map(|v| v.validator_id)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Lists the choices a validator held before their current vote.
+    ///
+    /// # Arguments
+    /// * `validator_id` - UUID of the validator
+    ///
+    /// # Returns
+    /// Each replaced choice with the time it was cast, oldest first
+    pub fn previous_choices(&self, validator_id: Uuid) -> Vec<(VoteChoice, DateTime<Utc>)> {
+        self.replaced_votes
+            .get(&validator_id)
+            .map(|votes| {
+                votes
+                    .iter()
+                    .map(|v| (v.choice.clone(), v.timestamp))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Counts validators taking part directly or through delegation.
+    ///
+    /// Includes every distinct direct voter plus each delegator who did not
+    /// vote but whose delegation chain resolves to someone who did.
+    pub fn effective_participation(&self) -> usize {
+        let direct: HashMap<Uuid, &Vote> = self.votes.iter().map(|v| (v.validator_id, v)).collect();
+        let delegated = self
+            .delegations
+            .keys()
+            .filter(|d| !direct.contains_key(d))
+            .filter(|d| self.resolve_delegation(**d, &direct).is_some())
+            .count();
+        direct.len() + delegated
+    }
+
+    /// Returns `true` if enough validators take part to meet the quorum.
+    ///
+    /// Delegators whose delegate voted count toward the quorum, see
+    /// [`Proposal::effective_participation`]. Always `true` when no quorum
+    /// is configured.
+    pub fn quorum_met(&self) -> bool {
+        self.quorum
+            .is_none_or(|q| self.effective_participation() >= q)
+    }
+
+    /// Returns `true` if the Yes and No votes carry enough weight to meet the weight quorum.
+    ///
+    /// Always `true` when no weight quorum is configured.
+    pub fn weight_quorum_met(&self) -> bool {
+        self.weight_quorum.is_none_or(|q| {
+            let tally = self.weighted_tally();
+            tally.yes_weight + tally.no_weight >= q
+        })
+    }
+
+    /// Calculates the fraction of eligible validators taking part.
+    ///
+    /// Each validator is counted once regardless of how many votes they cast,
+    /// and delegators count when their delegate voted.
+    ///
+    /// # Returns
+    /// Participation between 0.0 and 1.0, or 1.0 if `max_validators` is unset
+    pub fn participation(&self) -> f64 {
+        match self.max_validators {
+            Some(max) if max > 0 => (self.effective_participation() as f64 / max as f64).min(1.0),
+            _ => 1.0,
+        }
+    }
+
+    /// Measures how far the approval ratio is above the threshold at `now`.
+    ///
+    /// Uses the same threshold as evaluation, including the participation
+    /// adjustment and `min_threshold`.
+    ///
+    /// # Arguments
+    /// * `now` - Instant to compute the threshold at
+    ///
+    /// # Returns
+    /// Approval ratio minus threshold; zero or above means the ratio clears it,
+    /// as does a shortfall within `threshold_epsilon`
+    pub fn acceptance_margin(&self, now: DateTime<Utc>) -> f64 {
+        let elapsed = self.voting_window.elapsed(now);
+        let total = self.voting_window.total_duration();
+        self.current_approval_ratio() - self.threshold_at(elapsed, total)
+    }
+
+    /// Returns `true` if the approval ratio meets the threshold at `now`.
+    ///
+    /// Only the margin is considered; quorum, vetoes and the voting window
+    /// are left to [`Proposal::evaluate`].
+    ///
+    /// # Arguments
+    /// * `now` - Instant to compute the threshold at
+    pub fn is_passing(&self, now: DateTime<Utc>) -> bool {
+        self.acceptance_margin(now) + self.threshold_epsilon >= 0.0
+    }
+
+    /// Returns `true` if `ratio` clears `threshold` within `threshold_epsilon`.
+    fn meets_threshold(&self, ratio: f64, threshold: f64) -> bool {
+        ratio + self.threshold_epsilon >= threshold
+    }
+
+    /// Computes the approval threshold at `elapsed` seconds into the window.
+    ///
+    /// Applies the participation adjustment and then the `min_threshold` floor.
+    fn threshold_at(&self, elapsed: u64, total: u64) -> f64 {
+        threshold_calc_with_participation(
+            &self.threshold_model,
+            elapsed,
+            total,
+            self.participation(),
+        )
+        .max(self.min_threshold)
+    }
+
+    /// Calculates the current approval ratio based on weighted votes.
+    ///
+    /// The approval ratio is calculated as:
+    /// `yes_weight / (yes_weight + no_weight)`
+    ///
+    /// Each vote contributes its decayed weight scaled by its stake, and
+    /// delegated power follows the delegate's vote. Veto votes are never
+    /// counted; abstain votes only join the denominator under
+    /// [`AbstainMode::CountInDenominator`].
+    ///
+    /// Without delegations the ratio comes straight from running totals kept
+    /// up to date as votes are added, so it costs O(1) between extensions.
+    ///
+    /// # Returns
+    /// The approval ratio as a value between 0.0 and 1.0, or 0.0 if no votes
+    pub fn current_approval_ratio(&self) -> f64 {
+        if !self.delegations.is_empty() {
+            return self.weighted_tally().approval_ratio;
+        }
+
+        let tally = self.with_cached_weights(|cached| WeightedTally {
+            yes_weight: cached.yes_weight,
+            no_weight: cached.no_weight,
+            abstain_weight: cached.abstain_weight,
+            ..WeightedTally::default()
+        });
+        let total_weight = self.ratio_denominator(&tally);
+        if total_weight > 0.0 {
+            tally.yes_weight / total_weight
+        } else {
+            0.0
+        }
+    }
+
+    /// Calculates the approval ratio as it stood when the main window closed.
+    ///
+    /// Votes timestamped after [`Proposal::voting_deadline`], such as those
+    /// cast in the grace period, are ignored. A validator who changed their
+    /// vote after the deadline is counted with their last vote before it.
+    ///
+    /// # Returns
+    /// The approval ratio between 0.0 and 1.0, or 0.0 if no votes count
+    pub fn tally_at_window_close(&self) -> f64 {
+        let deadline = self.voting_deadline();
+        let mut frozen = self.clone();
+        frozen.votes = self
+            .votes
+            .iter()
+            .filter_map(|vote| {
+                if vote.timestamp <= deadline {
+                    return Some(vote.clone());
+                }
+                self.replaced_votes
+                    .get(&vote.validator_id)?
+                    .iter()
+                    .rev()
+                    .find(|v| v.timestamp <= deadline)
+                    .cloned()
+            })
+            .collect();
+        frozen.invalidate_weight_cache();
+        frozen.current_approval_ratio()
+    }
+
+    /// Breaks the approval ratio down into per-choice weights.
+    ///
+    /// Uses the same effective weights as [`Proposal::current_approval_ratio`],
+    /// including stake, revision penalties and delegation.
+    ///
+    /// # Returns
+    /// The Yes and No weights, abstention count, counted validators and ratio
+    pub fn weighted_tally(&self) -> WeightedTally {
+        let mut tally = WeightedTally::default();
+
+        for (vote, weight) in self.effective_weights() {
+            tally.total_validators += 1;
+            match vote.choice {
+                VoteChoice::Yes => tally.yes_weight += weight,
+                VoteChoice::No => tally.no_weight += weight,
+                VoteChoice::Abstain => {
+                    tally.abstain_count += 1;
+                    tally.abstain_weight += weight;
+                }
+                VoteChoice::Veto => {}
+            }
+        }
+
+        let total_weight = self.ratio_denominator(&tally);
+        if total_weight > 0.0 {
+            tally.approval_ratio = tally.yes_weight / total_weight;
+        }
+        tally
+    }
+
+    /// Measures how concentrated effective voting power is on this proposal.
+    ///
+    /// Computes the Gini coefficient of the counted weights, one per
+    /// validator, including weight exercised through delegation.
+    ///
+    /// # Returns
+    /// 0.0 when every validator carries equal weight (or nobody voted),
+    /// approaching 1.0 as power concentrates in a single validator
+    pub fn weight_concentration(&self) -> f64 {
+        let mut weights: Vec<f64> = self
+            .effective_weights()
+            .into_iter()
+            .map(|(_, w)| w)
+            .collect();
+        let total: f64 = weights.iter().sum();
+        if weights.is_empty() || total <= 0.0 {
+            return 0.0;
+        }
+
+        weights.sort_by(f64::total_cmp);
+        let n = weights.len() as f64;
+        let ranked: f64 = weights
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (i + 1) as f64 * w)
+            .sum();
+        2.0 * ranked / (n * total) - (n + 1.0) / n
+    }
+
+    /// Ranks validators by how much weight they brought to bear on the outcome.
+    ///
+    /// Each direct voter's effective weight includes decay, revision penalty
+    /// and stake; delegated weight is credited to the delegate whose vote it
+    /// followed. Abstentions and vetoes carry no influence on the ratio and
+    /// are listed with 0.0.
+    ///
+    /// # Returns
+    /// `(validator, influence)` pairs, highest first, ties broken by validator ID
+    pub fn influence_ranking(&self) -> Vec<(Uuid, f64)> {
+        let mut influence: HashMap<Uuid, f64> = HashMap::new();
+        for (vote, weight) in self.effective_weights() {
+            let counted = match vote.choice {
+                VoteChoice::Yes | VoteChoice::No => weight,
+                VoteChoice::Abstain | VoteChoice::Veto => 0.0,
+            };
+            *influence.entry(vote.validator_id).or_default() += counted;
+        }
+
+        let mut ranking: Vec<(Uuid, f64)> = influence.into_iter().collect();
+        ranking.sort_by(|(a_id, a), (b_id, b)| b.total_cmp(a).then(a_id.cmp(b_id)));
+        ranking
+    }
+
+    /// Returns the weight the approval ratio is measured against.
+    fn ratio_denominator(&self, tally: &WeightedTally) -> f64 {
+        match self.abstain_mode {
+            AbstainMode::Ignore => tally.yes_weight + tally.no_weight,
+            AbstainMode::CountInDenominator => {
+                tally.yes_weight + tally.no_weight + tally.abstain_weight
+            }
+        }
+    }
+
+    /// Pairs every counted vote with the weight it carries.
+    ///
+    /// Direct votes carry their decayed weight multiplied by the influence
+    /// of their stake under the proposal's `weight_mode`.
+    /// Each delegator who did not vote directly adds the decayed weight of
+    /// the vote their delegation chain resolves to; chains caught in a cycle,
+    /// ending without a vote or leaving the validator registry are skipped. Direct votes come first, in
+    /// the order of `votes`.
+    fn effective_weights(&self) -> Vec<(&Vote, f64)> {
+        let decayed = self.decayed_weights();
+        let direct: HashMap<Uuid, &Vote> = self.votes.iter().map(|v| (v.validator_id, v)).collect();
+        let decayed_by_validator: HashMap<Uuid, f64> = self
+            .votes
+            .iter()
+            .zip(&decayed)
+            .map(|(vote, weight)| (vote.validator_id, *weight))
+            .collect();
+
+        let mut weights: Vec<(&Vote, f64)> = self
+            .votes
+            .iter()
+            .zip(&decayed)
+            .map(|(vote, weight)| (vote, weight * self.weight_mode.influence(vote.stake)))
+            .collect();
+
+        for delegator in self.delegations.keys() {
+            if direct.contains_key(delegator) {
+                continue;
+            }
+            if let Some(vote) = self.resolve_delegation(*delegator, &direct) {
+                weights.push((vote, decayed_by_validator[&vote.validator_id]));
+            }
+        }
+
+        weights
+    }
+
+    /// Returns the decayed, revision-penalised weight of each vote in `votes`.
+    ///
+    /// Served from the weight cache when it was computed for the current
+    /// window and models; votes appended since then are weighed and added
+    /// to it.
+    fn decayed_weights(&self) -> Vec<f64> {
+        self.with_cached_weights(|cached| cached.weights.clone())
+    }
+
+    /// Brings the weight cache up to date and reads from it.
+    ///
+    /// Rebuilds the cache if it was computed for a different window or
+    /// models, then weighs any votes appended since it was last used.
+    fn with_cached_weights<R>(&self, read: impl FnOnce(&CachedWeights) -> R) -> R {
+        let mut cache = self.weight_cache.lock();
+        let cached = cache.get_or_insert_with(|| CachedWeights::empty_for(self));
+        if !cached.is_valid_for(self) {
+            *cached = CachedWeights::empty_for(self);
+        }
+
+        for vote in &self.votes[cached.weights.len()..] {
+            cached.push(vote);
+        }
+        read(cached)
+    }
+}
+
+/// Step-by-step constructor for [`Proposal`].
+///
+/// `title`, `duration`, `decay_model` and `threshold_model` are required;
+/// everything else falls back to the same defaults as [`Proposal::new`].
+///
+/// # Examples
+/// ```
+/// use verdyce_core::decay::DecayModel;
+/// use verdyce_core::models::proposal::Proposal;
+/// use verdyce_core::threshold::ThresholdModel;
+///
+/// let proposal = Proposal::builder()
+///     .title("Treasury grant")
+///     .duration(3600)
+///     .decay_model(DecayModel::Linear)
+///     .threshold_model(ThresholdModel::Linear(0.0, 0.5))
+///     .quorum(5)
+///     .build()
+///     .unwrap();
+/// assert_eq!(proposal.quorum, Some(5));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ProposalBuilder {
+    title: Option<String>,
+    description: String,
+    duration: Option<u64>,
+    grace_period: Option<u64>,
+    decay_model: Option<DecayModel>,
+    threshold_model: Option<ThresholdModel>,
+    quorum: Option<usize>,
+    tags: Vec<String>,
+    created_at: Option<DateTime<Utc>>,
+    start_time: Option<DateTime<Utc>>,
+}
+
+impl Proposal {
+    /// Starts building a proposal with [`ProposalBuilder`].
+    pub fn builder() -> ProposalBuilder {
+        ProposalBuilder::default()
+    }
+}
+
+impl ProposalBuilder {
+    /// Sets the human-readable title (required).
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description (defaults to empty).
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Sets the creation time, which is also when voting starts unless
+    /// [`ProposalBuilder::start_time`] is given.
+    ///
+    /// Defaults to the current time; required when the `clock` feature is disabled.
+    pub fn created_at(mut self, created_at: DateTime<Utc>) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    /// Sets when voting opens (defaults to the creation time).
+    ///
+    /// A start in the future schedules the proposal: its window stays
+    /// [`WindowState::NotStarted`] and evaluation leaves it pending until then.
+    pub fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Sets the voting period duration in seconds (required).
+    pub fn duration(mut self, duration: u64) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets the grace period in seconds (defaults to [`DEFAULT_GRACE_PERIOD`]).
+    pub fn grace_period(mut self, grace_period: u64) -> Self {
+        self.grace_period = Some(grace_period);
+        self
+    }
+
+    /// Sets how vote weights decay over time (required).
+    pub fn decay_model(mut self, decay_model: DecayModel) -> Self {
+        self.decay_model = Some(decay_model);
+        self
+    }
+
+    /// Sets how the approval threshold changes over time (required).
+    pub fn threshold_model(mut self, threshold_model: ThresholdModel) -> Self {
+        self.threshold_model = Some(threshold_model);
+        self
+    }
+
+    /// Sets the minimum number of distinct validators required for acceptance.
+    pub fn quorum(mut self, quorum: usize) -> Self {
+        self.quorum = Some(quorum);
+        self
+    }
+
+    /// Sets the tags used to categorise the proposal.
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Assembles and validates the proposal.
+    ///
+    /// # Returns
+    /// The proposal, or which required field is missing or what failed validation
+    pub fn build(self) -> Result<Proposal, BuildError> {
+        let title = self.title.ok_or(BuildError::MissingField("title"))?;
+        let duration = self.duration.ok_or(BuildError::MissingField("duration"))?;
+        let decay_model = self
+            .decay_model
+            .ok_or(BuildError::MissingField("decay_model"))?;
+        let threshold_model = self
+            .threshold_model
+            .ok_or(BuildError::MissingField("threshold_model"))?;
+        #[cfg(feature = "clock")]
+        let created_at = self.created_at.unwrap_or_else(Utc::now);
+        #[cfg(not(feature = "clock"))]
+        let created_at = self
+            .created_at
+            .ok_or(BuildError::MissingField("created_at"))?;
+
+        let mut proposal = Proposal::new_at(
+            title,
+            self.description,
+            duration,
+            decay_model,
+            threshold_model,
+            created_at,
+        );
+        if let Some(start_time) = self.start_time {
+            proposal.voting_window.start_time = start_time;
+        }
+        if let Some(grace_period) = self.grace_period {
+            proposal.voting_window.grace_period = grace_period;
+        }
+        proposal.quorum = self.quorum;
+        proposal.tags = self.tags;
+
+        proposal.validate()?;
+        Ok(proposal)
     }
 }
@@ -0,0 +1,125 @@
+//! # Proposal Templates
+//!
+//! Reusable voting configurations that operators can keep in a config file
+//! and stamp out proposals from.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::decay::DecayModel;
+use crate::models::proposal::Proposal;
+use crate::models::vote::AbstainMode;
+use crate::threshold::ThresholdModel;
+use crate::window::{DEFAULT_GRACE_PERIOD, ExtensionPolicy};
+
+/// Voting configuration shared by every proposal created from it.
+///
+/// Models use their serde representation, e.g. `"Linear"` or
+/// `{"Exponential": 0.01}`. Optional settings may be left out of the config.
+///
+/// # Examples
+/// ```
+/// use verdyce_core::decay::DecayModel;
+/// use verdyce_core::models::template::ProposalTemplate;
+///
+/// let template = ProposalTemplate::from_json(
+///     r#"{
+///         "duration": 3600,
+///         "decay_model": {"Exponential": 0.001},
+///         "threshold_model": {"Linear": [0.0001, 0.5]},
+///         "quorum": 3
+///     }"#,
+/// )
+/// .unwrap();
+/// assert_eq!(template.decay_model, DecayModel::Exponential(0.001));
+/// assert_eq!(template.grace_period, 30);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProposalTemplate {
+    /// Voting period duration in seconds
+    pub duration: u64,
+    /// Grace period after voting ends in seconds
+    #[serde(default = "default_grace_period")]
+    pub grace_period: u64,
+    /// Model for how vote weights decay over time
+    pub decay_model: DecayModel,
+    /// Model for how approval thresholds change over time
+    pub threshold_model: ThresholdModel,
+    /// Minimum number of distinct validators that must vote before acceptance
+    #[serde(default)]
+    pub quorum: Option<usize>,
+    /// Minimum total Yes and No weight that must be cast before acceptance
+    #[serde(default)]
+    pub weight_quorum: Option<f64>,
+    /// Floor applied to the time-based threshold
+    #[serde(default)]
+    pub min_threshold: f64,
+    /// Whether abstentions lower the approval ratio
+    #[serde(default)]
+    pub abstain_mode: AbstainMode,
+    /// When and by how much to extend the voting window
+    #[serde(default)]
+    pub extension_policy: Option<ExtensionPolicy>,
+    /// Labels given to every proposal created from the template
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+fn default_grace_period() -> u64 {
+    DEFAULT_GRACE_PERIOD
+}
+
+impl ProposalTemplate {
+    /// Parses a template from JSON.
+    ///
+    /// # Arguments
+    /// * `json` - Template config
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Creates a proposal from this template with voting starting now.
+    ///
+    /// # Arguments
+    /// * `title` - Human-readable title for the proposal
+    /// * `description` - Detailed description of the proposal
+    #[cfg(feature = "clock")]
+    pub fn instantiate(
+        &self,
+        title: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Proposal {
+        self.instantiate_at(title, description, Utc::now())
+    }
+
+    /// Creates a proposal from this template with voting starting at `now`.
+    ///
+    /// # Arguments
+    /// * `title` - Human-readable title for the proposal
+    /// * `description` - Detailed description of the proposal
+    /// * `now` - Creation time and start of the voting window
+    pub fn instantiate_at(
+        &self,
+        title: impl Into<String>,
+        description: impl Into<String>,
+        now: DateTime<Utc>,
+    ) -> Proposal {
+        let mut proposal = Proposal::new_at(
+            title.into(),
+            description.into(),
+            self.duration,
+            self.decay_model.clone(),
+            self.threshold_model.clone(),
+            now,
+        )
+        .with_min_threshold(self.min_threshold)
+        .with_abstain_mode(self.abstain_mode)
+        .with_tags(self.tags.clone());
+
+        proposal.voting_window.grace_period = self.grace_period;
+        proposal.quorum = self.quorum;
+        proposal.weight_quorum = self.weight_quorum;
+        proposal.extension_policy = self.extension_policy;
+        proposal
+    }
+}
@@ -2,9 +2,12 @@
 //!
 //! Defines vote structures and weight calculation logic for the consensus system.
 
+use std::fmt;
+
 use crate::decay::{DecayModel, weight_calc};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 /// Represents the choice made in a vote.
@@ -16,6 +19,8 @@ pub enum VoteChoice {
     No,
     /// Abstain from voting (doesn't count toward approval ratio)
     Abstain,
+    /// Block the proposal outright; only honoured from validators holding veto power
+    Veto,
 }
 
 /// Represents a single vote cast by a validator.
@@ -31,6 +36,194 @@ pub struct Vote {
     pub revision: u64,
     /// Optional reason for the vote or vote change
     pub reason: Option<String>,
+    /// Stake backing this vote; the decayed weight is multiplied by it
+    ///
+    /// Under [`VoteWeightMode::Quadratic`] this is the number of credits spent.
+    #[serde(default = "default_stake")]
+    pub stake: u64,
+    /// Whether the vote arrived during the grace period after the main window closed
+    #[serde(default)]
+    pub cast_during_grace: bool,
+    /// Whether the proposal was amended after this vote was cast and the vote carried over
+    #[serde(default)]
+    pub amended_after: bool,
+}
+
+impl Vote {
+    /// Returns the key identifying this vote for deduplication.
+    ///
+    /// Resubmitting a vote with the same key, choice and timestamp is a
+    /// no-op; any other vote from the validator replaces their current one.
+    pub fn dedup_key(&self) -> (Uuid, u64) {
+        (self.validator_id, self.revision)
+    }
+}
+
+fn default_stake() -> u64 {
+    1
+}
+
+/// A sealed vote: a hash of the choice that is revealed later.
+///
+/// Committing hides a validator's choice during the voting window so others
+/// can't bandwagon; the choice only counts once it is revealed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VoteCommitment {
+    /// Unique identifier of the committing validator
+    pub validator_id: Uuid,
+    /// Hex-encoded SHA-256 of the choice and nonce, see [`commitment_hash`]
+    pub commitment: String,
+    /// When the commitment was made; the revealed vote is weighted from this time
+    pub timestamp: DateTime<Utc>,
+    /// Stake backing the sealed vote, carried over to the revealed vote
+    #[serde(default = "default_stake")]
+    pub stake: u64,
+}
+
+/// Computes the commitment for a choice sealed with a secret nonce.
+///
+/// The hash covers the lowercase choice name (`yes`, `no`, `abstain` or
+/// `veto`) followed by the nonce.
+///
+/// # Arguments
+/// * `choice` - The choice being committed to
+/// * `nonce` - Secret chosen by the validator and disclosed on reveal
+///
+/// # Returns
+/// Lowercase hex-encoded SHA-256 digest
+pub fn commitment_hash(choice: &VoteChoice, nonce: &str) -> String {
+    let label = match choice {
+        VoteChoice::Yes => "yes",
+        VoteChoice::No => "no",
+        VoteChoice::Abstain => "abstain",
+        VoteChoice::Veto => "veto",
+    };
+    let digest = Sha256::new()
+        .chain_update(label)
+        .chain_update(nonce)
+        .finalize();
+    digest.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Reasons a vote can be refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VoteError {
+    /// No proposal exists with the given ID
+    ProposalNotFound,
+    /// The proposal is no longer accepting votes
+    ProposalClosed,
+    /// The proposal is waiting for proposals it depends on to be accepted
+    DependenciesPending,
+    /// The validator is not in the proposal's validator registry
+    NotEligible,
+    /// The vote's stake is above the proposal's `max_stake`
+    StakeTooHigh,
+    /// The vote is timestamped before the voting window opened
+    BeforeWindowStart,
+    /// The vote is timestamped after the grace period ended
+    AfterGracePeriod,
+    /// The vote is timestamped later than it was received, beyond the allowed clock skew
+    FutureTimestamp,
+    /// Commitments are only accepted while the voting window is open
+    CommitPhaseOver,
+    /// Commitments can only be revealed during the grace period
+    RevealNotOpen,
+    /// The validator has no outstanding commitment to reveal
+    NoCommitment,
+    /// The revealed choice and nonce don't match the commitment
+    CommitmentMismatch,
+    /// The same vote from this validator has already been recorded
+    Duplicate,
+}
+
+impl fmt::Display for VoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VoteError::ProposalNotFound => write!(f, "proposal not found"),
+            VoteError::ProposalClosed => write!(f, "proposal is not accepting votes"),
+            VoteError::DependenciesPending => {
+                write!(f, "proposal is waiting for its dependencies to be accepted")
+            }
+            VoteError::NotEligible => write!(f, "validator is not eligible to vote"),
+            VoteError::StakeTooHigh => write!(f, "vote stake exceeds the proposal's maximum"),
+            VoteError::BeforeWindowStart => {
+                write!(f, "vote is timestamped before the voting window opened")
+            }
+            VoteError::AfterGracePeriod => {
+                write!(f, "vote is timestamped after the grace period ended")
+            }
+            VoteError::FutureTimestamp => write!(f, "vote is timestamped in the future"),
+            VoteError::CommitPhaseOver => {
+                write!(f, "commitments are only accepted while voting is open")
+            }
+            VoteError::RevealNotOpen => {
+                write!(
+                    f,
+                    "commitments can only be revealed during the grace period"
+                )
+            }
+            VoteError::NoCommitment => write!(f, "validator has no commitment to reveal"),
+            VoteError::CommitmentMismatch => {
+                write!(f, "revealed vote does not match the commitment")
+            }
+            VoteError::Duplicate => write!(f, "vote has already been recorded"),
+        }
+    }
+}
+
+impl std::error::Error for VoteError {}
+
+/// How strongly changing a vote reduces its weight.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RevisionPenalty {
+    /// Weight is divided by `(1 + revision)^2`
+    #[default]
+    Quadratic,
+    /// Weight is divided by `1 + revision`
+    Linear,
+    /// Revisions carry no penalty; only decay applies
+    None,
+}
+
+impl RevisionPenalty {
+    /// Returns the divisor applied to a vote's weight for the given revision.
+    pub fn divisor(&self, revision: u64) -> f64 {
+        match self {
+            RevisionPenalty::Quadratic => (1 + revision).pow(2) as f64,
+            RevisionPenalty::Linear => (1 + revision) as f64,
+            RevisionPenalty::None => 1.0,
+        }
+    }
+}
+
+/// How a vote's stake translates into influence on the approval ratio.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum VoteWeightMode {
+    /// Influence grows linearly with stake
+    #[default]
+    Linear,
+    /// Influence is the square root of the stake (quadratic voting credits)
+    Quadratic,
+}
+
+impl VoteWeightMode {
+    /// Returns the multiplier applied to a vote's decayed weight for the given stake.
+    pub fn influence(&self, stake: u64) -> f64 {
+        match self {
+            VoteWeightMode::Linear => stake as f64,
+            VoteWeightMode::Quadratic => (stake as f64).sqrt(),
+        }
+    }
+}
+
+/// How abstentions affect a proposal's approval ratio.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AbstainMode {
+    /// Abstentions are left out of the ratio entirely
+    #[default]
+    Ignore,
+    /// Abstain weight is added to the denominator, lowering the ratio
+    CountInDenominator,
 }
 
 /// Calculates the effective weight of a vote based on timing and revision history.
@@ -63,6 +256,9 @@ pub struct Vote {
 ///     timestamp: start,
 ///     revision: 0,
 ///     reason: None,
+///     stake: 1,
+///     cast_during_grace: false,
+///     amended_after: false,
 /// };
 /// let weight = calculate_vote_weight(&vote, start, 3600, &DecayModel::Linear);
 /// assert!((weight - 1.0).abs() < 0.01); // Full weight at start
@@ -72,12 +268,65 @@ pub fn calculate_vote_weight(
     proposal_start: DateTime<Utc>,
     total_time: u64,
     decay_model: &DecayModel,
+) -> f64 {
+    calculate_vote_weight_with_penalty(
+        vote,
+        proposal_start,
+        total_time,
+        decay_model,
+        RevisionPenalty::Quadratic,
+    )
+}
+
+/// Calculates the effective weight of a vote using a specific revision penalty.
+///
+/// Behaves like [`calculate_vote_weight`], which always applies
+/// [`RevisionPenalty::Quadratic`].
+///
+/// # Arguments
+/// * `vote` - The vote to calculate weight for
+/// * `proposal_start` - When the proposal's voting period began
+/// * `total_time` - Total duration of the voting period in seconds
+/// * `decay_model` - The decay model to use for time-based weight reduction
+/// * `penalty` - How revisions reduce the weight
+///
+/// # Returns
+/// The effective weight of the vote (between 0.1 and 1.0)
+pub fn calculate_vote_weight_with_penalty(
+    vote: &Vote,
+    proposal_start: DateTime<Utc>,
+    total_time: u64,
+    decay_model: &DecayModel,
+    penalty: RevisionPenalty,
 ) -> f64 {
     let time_elapsed = (vote.timestamp - proposal_start).num_seconds().max(0) as u64;
-    let base_weight = weight_calc(decay_model, time_elapsed, total_time);
+    calculate_vote_weight_at_elapsed(vote, time_elapsed, total_time, decay_model, penalty)
+}
+
+/// Calculates the effective weight of a vote cast `elapsed` seconds into voting.
+///
+/// Unlike [`calculate_vote_weight_with_penalty`], the vote's own timestamp
+/// is ignored, so the caller can supply an elapsed time that leaves out
+/// paused intervals of the window.
+///
+/// # Arguments
+/// * `vote` - The vote to calculate weight for
+/// * `elapsed` - Voting time in seconds that had elapsed when the vote was cast
+/// * `total_time` - Total duration of the voting period in seconds
+/// * `decay_model` - The decay model to use for time-based weight reduction
+/// * `penalty` - How revisions reduce the weight
+///
+/// # Returns
+/// The effective weight of the vote (between 0.1 and 1.0)
+pub fn calculate_vote_weight_at_elapsed(
+    vote: &Vote,
+    elapsed: u64,
+    total_time: u64,
+    decay_model: &DecayModel,
+    penalty: RevisionPenalty,
+) -> f64 {
+    let base_weight = weight_calc(decay_model, elapsed, total_time);
 
-    // Revision penalty: weight / (1 + revisions)^2
-    let penalty = (1 + vote.revision).pow(2) as f64;
-    let penalized_weight = base_weight / penalty;
+    let penalized_weight = base_weight / penalty.divisor(vote.revision);
     penalized_weight.max(0.1)
 }
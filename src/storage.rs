@@ -0,0 +1,245 @@
+//! # Proposal Storage
+//!
+//! Abstraction over where proposals are persisted, so the engine can be backed
+//! by memory, files, or a database without depending on any of them.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::models::proposal::Proposal;
+
+/// A backend capable of persisting proposals.
+///
+/// Implementations decide how proposals are stored; the engine only relies on
+/// these operations to save and reload its state.
+pub trait ProposalStore {
+    /// Saves a proposal, replacing any stored proposal with the same ID.
+    ///
+    /// # Arguments
+    /// * `proposal` - The proposal to save
+    fn save(&mut self, proposal: &Proposal);
+
+    /// Loads a single proposal by ID.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to load
+    ///
+    /// # Returns
+    /// `Some(Proposal)` if stored, `None` otherwise
+    fn load(&self, proposal_id: Uuid) -> Option<Proposal>;
+
+    /// Loads every stored proposal.
+    fn load_all(&self) -> Vec<Proposal>;
+
+    /// Deletes a proposal by ID. Deleting a missing proposal is a no-op.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to delete
+    fn delete(&mut self, proposal_id: Uuid);
+}
+
+/// An asynchronous backend capable of persisting proposals.
+///
+/// The async counterpart to [`ProposalStore`], for backends such as network
+/// databases that shouldn't block the caller. Used by
+/// [`AsyncEngine`](crate::async_engine::AsyncEngine).
+pub trait AsyncProposalStore {
+    /// Saves a proposal, replacing any stored proposal with the same ID.
+    ///
+    /// # Arguments
+    /// * `proposal` - The proposal to save
+    fn save(&mut self, proposal: &Proposal) -> impl Future<Output = ()> + Send;
+
+    /// Loads a single proposal by ID.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to load
+    ///
+    /// # Returns
+    /// `Some(Proposal)` if stored, `None` otherwise
+    fn load(&self, proposal_id: Uuid) -> impl Future<Output = Option<Proposal>> + Send;
+
+    /// Deletes a proposal by ID. Deleting a missing proposal is a no-op.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to delete
+    fn delete(&mut self, proposal_id: Uuid) -> impl Future<Output = ()> + Send;
+}
+
+/// A [`ProposalStore`] that keeps proposals in memory.
+///
+/// Useful for tests and for short-lived engines that don't need durability.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryStore {
+    proposals: HashMap<Uuid, Proposal>,
+}
+
+impl InMemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ProposalStore for InMemoryStore {
+    fn save(&mut self, proposal: &Proposal) {
+        self.proposals.insert(proposal.id, proposal.clone());
+    }
+
+    fn load(&self, proposal_id: Uuid) -> Option<Proposal> {
+        self.proposals.get(&proposal_id).cloned()
+    }
+
+    /// Returns proposals ordered by creation time, oldest first.
+    fn load_all(&self) -> Vec<Proposal> {
+        let mut proposals: Vec<Proposal> = self.proposals.values().cloned().collect();
+        proposals.sort_by_key(|p| (p.created_at, p.id));
+        proposals
+    }
+
+    fn delete(&mut self, proposal_id: Uuid) {
+        self.proposals.remove(&proposal_id);
+    }
+}
+
+impl AsyncProposalStore for InMemoryStore {
+    async fn save(&mut self, proposal: &Proposal) {
+        ProposalStore::save(self, proposal);
+    }
+
+    async fn load(&self, proposal_id: Uuid) -> Option<Proposal> {
+        ProposalStore::load(self, proposal_id)
+    }
+
+    async fn delete(&mut self, proposal_id: Uuid) {
+        ProposalStore::delete(self, proposal_id);
+    }
+}
+
+/// Reasons a sealed proposal can fail to open.
+#[derive(Debug)]
+pub enum IntegrityError {
+    /// The stored data isn't a sealed proposal at all
+    Corrupted(serde_json::Error),
+    /// The payload no longer matches its checksum
+    ChecksumMismatch {
+        /// Checksum recorded when the proposal was sealed
+        expected: String,
+        /// Checksum of the payload as loaded
+        actual: String,
+    },
+    /// The payload is intact but doesn't deserialize into a [`Proposal`]
+    Schema(serde_json::Error),
+}
+
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityError::Corrupted(e) => write!(f, "stored proposal is corrupted: {e}"),
+            IntegrityError::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "stored proposal checksum mismatch: expected {expected}, found {actual}"
+            ),
+            IntegrityError::Schema(e) => {
+                write!(f, "stored proposal does not match the current schema: {e}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for IntegrityError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IntegrityError::Corrupted(e) | IntegrityError::Schema(e) => Some(e),
+            IntegrityError::ChecksumMismatch { .. } => None,
+        }
+    }
+}
+
+/// A proposal serialized together with a checksum of its contents.
+///
+/// Backends that store proposals as bytes, such as key-value stores, can
+/// persist this instead of the bare proposal so that corruption is reported
+/// as [`IntegrityError::ChecksumMismatch`] rather than as an opaque
+/// deserialization error.
+///
+/// # Examples
+/// ```
+/// use verdyce_core::decay::DecayModel;
+/// use verdyce_core::models::proposal::Proposal;
+/// use verdyce_core::storage::SealedProposal;
+/// use verdyce_core::threshold::ThresholdModel;
+///
+/// let proposal = Proposal::builder()
+///     .title("Treasury grant")
+///     .duration(3600)
+///     .decay_model(DecayModel::Linear)
+///     .threshold_model(ThresholdModel::Linear(0.0, 0.5))
+///     .build()
+///     .unwrap();
+/// let stored = SealedProposal::seal(&proposal).unwrap().to_json().unwrap();
+/// let loaded = SealedProposal::from_json(&stored).unwrap();
+/// assert_eq!(loaded.id, proposal.id);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SealedProposal {
+    /// Lowercase hex-encoded SHA-256 of `payload`
+    pub checksum: String,
+    /// Canonical JSON of the proposal, with object keys sorted
+    pub payload: String,
+}
+
+impl SealedProposal {
+    /// Serializes a proposal to canonical JSON and records its checksum.
+    ///
+    /// # Arguments
+    /// * `proposal` - The proposal to seal
+    pub fn seal(proposal: &Proposal) -> Result<Self, serde_json::Error> {
+        let payload = serde_json::to_value(proposal)?.to_string();
+        Ok(Self {
+            checksum: sha256_hex(&payload),
+            payload,
+        })
+    }
+
+    /// Verifies the checksum and deserializes the proposal.
+    ///
+    /// # Returns
+    /// The proposal, or [`IntegrityError::ChecksumMismatch`] if the payload
+    /// was altered and [`IntegrityError::Schema`] if it is intact but unreadable
+    pub fn open(&self) -> Result<Proposal, IntegrityError> {
+        let actual = sha256_hex(&self.payload);
+        if actual != self.checksum {
+            return Err(IntegrityError::ChecksumMismatch {
+                expected: self.checksum.clone(),
+                actual,
+            });
+        }
+        serde_json::from_str(&self.payload).map_err(IntegrityError::Schema)
+    }
+
+    /// Serializes the sealed proposal for storage.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Parses stored data and opens the sealed proposal inside it.
+    ///
+    /// # Arguments
+    /// * `json` - Data previously produced by [`SealedProposal::to_json`]
+    pub fn from_json(json: &str) -> Result<Proposal, IntegrityError> {
+        let sealed: Self = serde_json::from_str(json).map_err(IntegrityError::Corrupted)?;
+        sealed.open()
+    }
+}
+
+fn sha256_hex(data: &str) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
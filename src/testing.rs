@@ -0,0 +1,150 @@
+//! # Testing Utilities
+//!
+//! Offline stand-ins for persistent storage so downstream crates can exercise
+//! full voting flows without a database. Enabled with the `testing` feature.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::engine::Engine;
+use crate::models::proposal::Proposal;
+use crate::models::vote::{Vote, VoteError};
+use crate::storage::{InMemoryStore, ProposalStore};
+
+/// A [`ProposalStore`] whose contents stay inspectable after it is handed to an engine.
+///
+/// Clones share the same underlying storage, so a test can keep one handle
+/// while the engine owns another.
+#[derive(Debug, Clone, Default)]
+pub struct MockStore {
+    inner: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    store: InMemoryStore,
+    saves: usize,
+    deletes: usize,
+}
+
+impl MockStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns how many times [`ProposalStore::save`] has been called.
+    pub fn save_count(&self) -> usize {
+        self.state().saves
+    }
+
+    /// Returns how many times [`ProposalStore::delete`] has been called.
+    pub fn delete_count(&self) -> usize {
+        self.state().deletes
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl ProposalStore for MockStore {
+    fn save(&mut self, proposal: &Proposal) {
+        let mut state = self.state();
+        state.saves += 1;
+        state.store.save(proposal);
+    }
+
+    fn load(&self, proposal_id: Uuid) -> Option<Proposal> {
+        self.state().store.load(proposal_id)
+    }
+
+    fn load_all(&self) -> Vec<Proposal> {
+        self.state().store.load_all()
+    }
+
+    fn delete(&mut self, proposal_id: Uuid) {
+        let mut state = self.state();
+        state.deletes += 1;
+        state.store.delete(proposal_id);
+    }
+}
+
+/// An [`Engine`] backed by a [`MockStore`] that persists after every operation.
+///
+/// Mirrors a create → vote → evaluate flow against a real backend: each call
+/// writes through to the store, and [`InMemoryEngine::load`] reads back what
+/// was saved rather than the engine's in-memory copy.
+pub struct InMemoryEngine {
+    engine: Engine,
+    store: MockStore,
+}
+
+impl Default for InMemoryEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryEngine {
+    pub fn new() -> Self {
+        let store = MockStore::new();
+        Self {
+            engine: Engine::with_store(Box::new(store.clone())),
+            store,
+        }
+    }
+
+    /// Adds a proposal to the engine and saves it.
+    ///
+    /// # Returns
+    /// The ID of the stored proposal
+    pub fn create_proposal(&mut self, proposal: Proposal) -> Uuid {
+        let id = proposal.id;
+        self.store.save(&proposal);
+        self.engine.add_proposal(proposal);
+        id
+    }
+
+    /// Casts a vote and saves the updated proposal.
+    ///
+    /// # Arguments
+    /// * `proposal_id` - UUID of the proposal to vote on
+    /// * `vote` - The vote to cast
+    pub fn cast_vote(&mut self, proposal_id: Uuid, vote: Vote) -> Result<(), VoteError> {
+        self.engine.try_cast_vote(proposal_id, vote)?;
+        if let Some(proposal) = self.engine.get_proposal(proposal_id) {
+            self.store.save(proposal);
+        }
+        Ok(())
+    }
+
+    /// Evaluates every proposal at `now` and saves the results.
+    pub fn evaluate(&mut self, now: DateTime<Utc>) {
+        self.engine.evaluate_all(now);
+        self.engine.persist_all();
+    }
+
+    /// Loads a proposal from the store.
+    pub fn load(&self, proposal_id: Uuid) -> Option<Proposal> {
+        self.store.load(proposal_id)
+    }
+
+    /// Returns a handle to the backing store.
+    pub fn store(&self) -> &MockStore {
+        &self.store
+    }
+
+    /// Returns the wrapped engine.
+    pub fn engine(&self) -> &Engine {
+        &self.engine
+    }
+
+    /// Returns the wrapped engine mutably.
+    pub fn engine_mut(&mut self) -> &mut Engine {
+        &mut self.engine
+    }
+}
@@ -3,14 +3,22 @@
 //! Implements different models for how approval thresholds change over time,
 //! allowing for quick early consensus while requiring higher scrutiny later.
 
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+
 use serde::{Deserialize, Serialize};
 
+use crate::math;
+
 /// Models for how approval thresholds change over time.
 ///
 /// Each model provides a different progression curve:
 /// - Linear: Steady increase over time
 /// - Exponential: Rapid early increase, slower later
 /// - Sigmoid: S-curve progression with smooth transitions
+/// - ShiftedSigmoid: S-curve whose steepest point can be placed anywhere in the window
+/// - TimeAndParticipation: Any of the above, raised further when turnout is low
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ThresholdModel {
     /// Linear threshold progression: `threshold = t * rate + start`
@@ -33,12 +41,174 @@ pub enum ThresholdModel {
     /// * `rate` - Steepness of the curve
     /// * `floor` - Minimum threshold value
     Sigmoid(f64, f64),
+
+    /// Sigmoid threshold progression with a configurable inflection point
+    ///
+    /// `Sigmoid(rate, floor)` is equivalent to a `midpoint` of 0.5.
+    ///
+    /// # Parameters
+    /// * `rate` - Steepness of the curve
+    /// * `floor` - Minimum threshold value
+    /// * `midpoint` - Fraction of the window (clamped to `[0, 1]`) where the curve is steepest
+    ShiftedSigmoid {
+        rate: f64,
+        floor: f64,
+        midpoint: f64,
+    },
+
+    /// Time-based threshold that is raised when participation is low
+    ///
+    /// The effective threshold is `base + participation_weight * (1 - participation)`,
+    /// so full participation yields the plain `base` curve.
+    ///
+    /// # Parameters
+    /// * `base` - Underlying time-based threshold model
+    /// * `participation_weight` - How much missing participation raises the threshold
+    TimeAndParticipation {
+        base: Box<ThresholdModel>,
+        participation_weight: f64,
+    },
+}
+
+/// Errors produced when validating a [`ThresholdModel`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThresholdError {
+    /// Sigmoid rate of zero flattens the curve to a constant
+    ZeroRate,
+    /// Rate parameter is negative, so the threshold would fall over time
+    NegativeRate(f64),
+    /// Start, floor or base value lies outside `[0, 1]`
+    StartOutOfRange(f64),
+    /// Participation weight is negative, so low turnout would lower the threshold
+    NegativeParticipationWeight(f64),
+    /// The unclamped threshold left the default bounds
+    OutOfBounds(f64),
+    /// Explicit bounds are inverted or lie outside `[0, 1]`
+    InvalidBounds { lo: f64, hi: f64 },
+}
+
+impl fmt::Display for ThresholdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThresholdError::ZeroRate => write!(f, "sigmoid threshold rate must be non-zero"),
+            ThresholdError::NegativeRate(r) => {
+                write!(f, "threshold rate must not be negative, got {r}")
+            }
+            ThresholdError::StartOutOfRange(s) => {
+                write!(f, "threshold start must be within [0, 1], got {s}")
+            }
+            ThresholdError::NegativeParticipationWeight(w) => {
+                write!(f, "participation weight must not be negative, got {w}")
+            }
+            ThresholdError::OutOfBounds(v) => write!(
+                f,
+                "threshold {v} lies outside [{DEFAULT_MIN_THRESHOLD}, {DEFAULT_MAX_THRESHOLD}]"
+            ),
+            ThresholdError::InvalidBounds { lo, hi } => write!(
+                f,
+                "threshold bounds must satisfy 0 <= lo <= hi <= 1, got lo={lo}, hi={hi}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ThresholdError {}
+
+impl ThresholdModel {
+    /// Checks that the model parameters describe a sensible threshold curve.
+    ///
+    /// A zero rate is only rejected for `Sigmoid`, where it silently flattens
+    /// the curve; `Linear(0.0, start)` remains a valid constant threshold.
+    ///
+    /// # Returns
+    /// `Ok(())` if the model is valid, or the first problem found
+    pub fn validate(&self) -> Result<(), ThresholdError> {
+        match self {
+            ThresholdModel::Linear(r, s) | ThresholdModel::Exponential(r, s) => {
+                check_rate(*r)?;
+                check_start(*s)
+            }
+            ThresholdModel::Sigmoid(r, s)
+            | ThresholdModel::ShiftedSigmoid {
+                rate: r, floor: s, ..
+            } => {
+                if *r == 0.0 {
+                    return Err(ThresholdError::ZeroRate);
+                }
+                check_rate(*r)?;
+                check_start(*s)
+            }
+            ThresholdModel::TimeAndParticipation {
+                base,
+                participation_weight,
+            } => {
+                if *participation_weight < 0.0 {
+                    return Err(ThresholdError::NegativeParticipationWeight(
+                        *participation_weight,
+                    ));
+                }
+                base.validate()
+            }
+        }
+    }
+
+    /// Returns the value the curve starts from, before any bounds are applied.
+    fn start(&self) -> f64 {
+        match self {
+            ThresholdModel::Linear(_, s)
+            | ThresholdModel::Exponential(_, s)
+            | ThresholdModel::Sigmoid(_, s)
+            | ThresholdModel::ShiftedSigmoid { floor: s, .. } => *s,
+            ThresholdModel::TimeAndParticipation { base, .. } => base.start(),
+        }
+    }
+
+    /// Samples the threshold curve at evenly spaced times across `[0, total]`.
+    ///
+    /// # Arguments
+    /// * `total` - Total voting period duration (seconds)
+    /// * `points` - Number of samples; values below 2 yield just the endpoints
+    ///
+    /// # Returns
+    /// `(t, threshold)` pairs from [`threshold_calc`], starting at 0 and ending at `total`
+    pub fn sample(&self, total: u64, points: usize) -> Vec<(u64, f64)> {
+        let points = points.max(2);
+        (0..points)
+            .map(|i| {
+                let t = (total as u128 * i as u128 / (points - 1) as u128) as u64;
+                (t, threshold_calc(self, t, total))
+            })
+            .collect()
+    }
+}
+
+fn check_rate(rate: f64) -> Result<(), ThresholdError> {
+    if rate < 0.0 {
+        Err(ThresholdError::NegativeRate(rate))
+    } else {
+        Ok(())
+    }
 }
 
+fn check_start(start: f64) -> Result<(), ThresholdError> {
+    if (0.0..=1.0).contains(&start) {
+        Ok(())
+    } else {
+        Err(ThresholdError::StartOutOfRange(start))
+    }
+}
+
+/// Default lower bound applied by [`threshold_calc`].
+pub const DEFAULT_MIN_THRESHOLD: f64 = 0.35;
+
+/// Default upper bound applied by [`threshold_calc`].
+pub const DEFAULT_MAX_THRESHOLD: f64 = 0.9;
+
 /// Calculates the approval threshold at a given time using the specified model.
 ///
 /// All models enforce bounds between 0.35 and 0.9 to ensure reasonable
-/// threshold ranges regardless of parameters.
+/// threshold ranges regardless of parameters. Use [`threshold_calc_bounded`]
+/// when a different range is required.
 ///
 /// # Arguments
 /// * `model` - The threshold model to use
@@ -57,21 +227,252 @@ pub enum ThresholdModel {
 /// assert!((threshold - 0.68).abs() < 0.01);
 /// ```
 pub fn threshold_calc(model: &ThresholdModel, t: u64, total: u64) -> f64 {
+    threshold_calc_raw(model, t, total).clamp(DEFAULT_MIN_THRESHOLD, DEFAULT_MAX_THRESHOLD)
+}
+
+/// What [`threshold_calc_checked`] does when a model leaves the default bounds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClampPolicy {
+    /// Silently clamp into the bounds, like [`threshold_calc`]
+    #[default]
+    Clamp,
+    /// Report the unclamped value as [`ThresholdError::OutOfBounds`]
+    ErrorOnOverflow,
+}
+
+/// Calculates the approval threshold, optionally refusing to clamp.
+///
+/// Useful for catching misconfigured models whose curves run past the
+/// default 0.35-0.9 bounds instead of having the excess silently hidden.
+///
+/// # Arguments
+/// * `model` - The threshold model to use
+/// * `t` - Time elapsed since voting started (seconds)
+/// * `total` - Total voting period duration (seconds)
+/// * `policy` - Whether out-of-range values are clamped or reported
+///
+/// # Returns
+/// The threshold, or the unclamped value if it is out of range under
+/// [`ClampPolicy::ErrorOnOverflow`]
+///
+/// # Examples
+/// ```
+/// use verdyce_core::threshold::{ClampPolicy, ThresholdError, ThresholdModel, threshold_calc_checked};
+///
+/// let model = ThresholdModel::Linear(0.001, 0.5);
+/// assert_eq!(threshold_calc_checked(&model, 3600, 3600, ClampPolicy::Clamp), Ok(0.9));
+/// assert!(matches!(
+///     threshold_calc_checked(&model, 3600, 3600, ClampPolicy::ErrorOnOverflow),
+///     Err(ThresholdError::OutOfBounds(_))
+/// ));
+/// ```
+pub fn threshold_calc_checked(
+    model: &ThresholdModel,
+    t: u64,
+    total: u64,
+    policy: ClampPolicy,
+) -> Result<f64, ThresholdError> {
+    let raw = threshold_calc_raw(model, t, total);
+    match policy {
+        ClampPolicy::Clamp => Ok(raw.clamp(DEFAULT_MIN_THRESHOLD, DEFAULT_MAX_THRESHOLD)),
+        ClampPolicy::ErrorOnOverflow
+            if !(DEFAULT_MIN_THRESHOLD..=DEFAULT_MAX_THRESHOLD).contains(&raw) =>
+        {
+            Err(ThresholdError::OutOfBounds(raw))
+        }
+        ClampPolicy::ErrorOnOverflow => Ok(raw),
+    }
+}
+
+/// Returns `true` if [`threshold_calc`] clamps the model's value at time `t`.
+///
+/// # Arguments
+/// * `model` - The threshold model to use
+/// * `t` - Time elapsed since voting started (seconds)
+/// * `total` - Total voting period duration (seconds)
+pub fn is_clamped(model: &ThresholdModel, t: u64, total: u64) -> bool {
+    !(DEFAULT_MIN_THRESHOLD..=DEFAULT_MAX_THRESHOLD).contains(&threshold_calc_raw(model, t, total))
+}
+
+/// Calculates the approval threshold at a given time, clamped to explicit bounds.
+///
+/// # Arguments
+/// * `model` - The threshold model to use
+/// * `t` - Time elapsed since voting started (seconds)
+/// * `total` - Total voting period duration (seconds)
+/// * `lo` - Lowest threshold the model may produce
+/// * `hi` - Highest threshold the model may produce
+///
+/// # Returns
+/// Approval threshold between `lo` and `hi`, or
+/// [`ThresholdError::InvalidBounds`] if `lo > hi` or either bound lies
+/// outside `[0, 1]`
+///
+/// # Examples
+/// ```
+/// use verdyce_core::threshold::{ThresholdModel, threshold_calc_bounded};
+///
+/// // A constitutional change may require up to 95% approval
+/// let threshold =
+///     threshold_calc_bounded(&ThresholdModel::Linear(0.001, 0.5), 3600, 3600, 0.35, 0.95).unwrap();
+/// assert!((threshold - 0.95).abs() < 0.001);
+/// ```
+pub fn threshold_calc_bounded(
+    model: &ThresholdModel,
+    t: u64,
+    total: u64,
+    lo: f64,
+    hi: f64,
+) -> Result<f64, ThresholdError> {
+    if !((0.0..=1.0).contains(&lo) && (0.0..=1.0).contains(&hi) && lo <= hi) {
+        return Err(ThresholdError::InvalidBounds { lo, hi });
+    }
+    Ok(threshold_calc_raw(model, t, total).clamp(lo, hi))
+}
+
+/// Calculates the approval threshold taking voter participation into account.
+///
+/// For [`ThresholdModel::TimeAndParticipation`] the threshold rises as
+/// participation drops; all other models ignore `participation` and behave
+/// exactly like [`threshold_calc`]. The result is clamped to the default bounds.
+///
+/// # Arguments
+/// * `model` - The threshold model to use
+/// * `t` - Time elapsed since voting started (seconds)
+/// * `total` - Total voting period duration (seconds)
+/// * `participation` - Fraction (0.0-1.0) of eligible validators that have voted
+///
+/// # Returns
+/// Approval threshold between 0.35 and 0.9
+///
+/// # Examples
+/// ```
+/// use verdyce_core::threshold::{ThresholdModel, threshold_calc_with_participation};
+///
+/// let model = ThresholdModel::TimeAndParticipation {
+///     base: Box::new(ThresholdModel::Linear(0.0, 0.5)),
+///     participation_weight: 0.2,
+/// };
+/// let low = threshold_calc_with_participation(&model, 0, 3600, 0.25);
+/// let full = threshold_calc_with_participation(&model, 0, 3600, 1.0);
+/// assert!((low - 0.65).abs() < 0.001);
+/// assert!((full - 0.5).abs() < 0.001);
+/// ```
+pub fn threshold_calc_with_participation(
+    model: &ThresholdModel,
+    t: u64,
+    total: u64,
+    participation: f64,
+) -> f64 {
+    let penalty = match model {
+        ThresholdModel::TimeAndParticipation {
+            participation_weight,
+            ..
+        } => participation_weight * (1.0 - participation.clamp(0.0, 1.0)),
+        _ => 0.0,
+    };
+    (threshold_calc_raw(model, t, total) + penalty)
+        .clamp(DEFAULT_MIN_THRESHOLD, DEFAULT_MAX_THRESHOLD)
+}
+
+/// Calculates how quickly the approval threshold is changing at time `t`.
+///
+/// Uses the analytic derivative of each model. The slope is zero wherever the
+/// default 0.35-0.9 clamp in [`threshold_calc`] is active, since the reported
+/// threshold is flat there. `TimeAndParticipation` reports the slope of its
+/// base curve, as participation does not depend on time.
+///
+/// # Arguments
+/// * `model` - The threshold model to use
+/// * `t` - Time elapsed since voting started (seconds)
+/// * `total` - Total voting period duration (seconds)
+///
+/// # Returns
+/// Rate of change of the threshold per second
+///
+/// # Examples
+/// ```
+/// use verdyce_core::threshold::{ThresholdModel, threshold_slope};
+///
+/// let slope = threshold_slope(&ThresholdModel::Linear(0.0001, 0.5), 1800, 3600);
+/// assert!((slope - 0.0001).abs() < 1e-12);
+/// ```
+pub fn threshold_slope(model: &ThresholdModel, t: u64, total: u64) -> f64 {
+    if is_clamped(model, t, total) {
+        return 0.0;
+    }
+    raw_slope(model, t, total)
+}
+
+/// Computes the derivative of the unclamped threshold curve.
+fn raw_slope(model: &ThresholdModel, t: u64, total: u64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
     match model {
-        ThresholdModel::Linear(r, s) => {
-            let thres = t as f64 * r + s;
-            thres.clamp(0.35, 0.9)
+        ThresholdModel::Linear(r, _) => *r,
+        ThresholdModel::Exponential(r, s) => r * (1.0 - s) * math::exp(-r * t as f64),
+        ThresholdModel::Sigmoid(r, s) => {
+            let sigmoid = sigmoid_at(*r, 0.5, t, total);
+            (1.0 - s) * r * sigmoid * (1.0 - sigmoid) / total as f64
         }
-        ThresholdModel::Exponential(r, s) => {
-            let growth = 1.0 - (-r * t as f64).exp();
-            let thres = s + (1.0 - s) * growth;
-            thres.clamp(0.35, 0.9)
+        ThresholdModel::ShiftedSigmoid {
+            rate,
+            floor,
+            midpoint,
+        } => {
+            let sigmoid = sigmoid_at(*rate, *midpoint, t, total);
+            (1.0 - floor) * rate * sigmoid * (1.0 - sigmoid) / total as f64
         }
-        ThresholdModel::Sigmoid(r, s) => {
-            let x = t as f64 / total as f64;
-            let sigmoid = 1.0 / (1.0 + (-r * (x - 0.5)).exp());
-            let thres = s + (1.0 - s) * sigmoid;
-            thres.clamp(0.35, 0.9)
+        ThresholdModel::TimeAndParticipation { base, .. } => raw_slope(base, t, total),
+    }
+}
+
+/// Evaluates the logistic function at `t / total`, centred on `midpoint`.
+fn sigmoid_at(rate: f64, midpoint: f64, t: u64, total: u64) -> f64 {
+    let x = t as f64 / total as f64;
+    1.0 / (1.0 + math::exp(-rate * (x - midpoint.clamp(0.0, 1.0))))
+}
+
+/// Calculates the approval threshold before any bounds are applied.
+///
+/// This is the curve that [`threshold_calc`] clamps to 0.35-0.9; comparing
+/// the two (or calling [`is_clamped`]) shows when the clamp is in effect.
+/// Participation penalties are not included. A zero-length period yields
+/// the model's starting value.
+///
+/// # Arguments
+/// * `model` - The threshold model to use
+/// * `t` - Time elapsed since voting started (seconds)
+/// * `total` - Total voting period duration (seconds)
+///
+/// # Returns
+/// The unclamped threshold, which may lie outside `[0, 1]`
+///
+/// # Examples
+/// ```
+/// use verdyce_core::threshold::{ThresholdModel, threshold_calc, threshold_calc_raw};
+///
+/// let model = ThresholdModel::Linear(0.001, 0.5);
+/// assert!((threshold_calc_raw(&model, 3600, 3600) - 4.1).abs() < 1e-9);
+/// assert_eq!(threshold_calc(&model, 3600, 3600), 0.9);
+/// ```
+pub fn threshold_calc_raw(model: &ThresholdModel, t: u64, total: u64) -> f64 {
+    if total == 0 {
+        return model.start();
+    }
+    match model {
+        ThresholdModel::Linear(r, s) => t as f64 * r + s,
+        ThresholdModel::Exponential(r, s) => {
+            let growth = 1.0 - math::exp(-r * t as f64);
+            s + (1.0 - s) * growth
         }
+        ThresholdModel::Sigmoid(r, s) => s + (1.0 - s) * sigmoid_at(*r, 0.5, t, total),
+        ThresholdModel::ShiftedSigmoid {
+            rate,
+            floor,
+            midpoint,
+        } => floor + (1.0 - floor) * sigmoid_at(*rate, *midpoint, t, total),
+        ThresholdModel::TimeAndParticipation { base, .. } => threshold_calc_raw(base, t, total),
     }
 }
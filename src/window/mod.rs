@@ -2,9 +2,12 @@
 //!
 //! Manages the timing and state of voting periods, including extensions and grace periods.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Grace period in seconds used when a proposal does not specify one.
+pub const DEFAULT_GRACE_PERIOD: u64 = 30;
+
 /// Represents the current state of a voting window.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WindowState {
@@ -20,10 +23,41 @@ pub enum WindowState {
     Expired,
 }
 
+impl WindowState {
+    /// Returns `true` if a window in this state can move directly to `other`.
+    ///
+    /// Windows move forward through NotStarted, Open, GracePeriod and
+    /// Expired. An extension moves the window to Extended, including one
+    /// applied before the start, during the grace period or by reopening
+    /// an expired window, and an extended window never returns to Open. A
+    /// state never transitions to itself. Sampling [`VotingWindow::state`]
+    /// infrequently can skip intermediate states.
+    ///
+    /// # Arguments
+    /// * `other` - The state to move to
+    pub fn can_transition_to(&self, other: &WindowState) -> bool {
+        self.next_states().contains(other)
+    }
+
+    /// Returns every state this one can move to directly.
+    ///
+    /// # Returns
+    /// The legal successor states
+    pub fn next_states(&self) -> Vec<WindowState> {
+        match self {
+            WindowState::NotStarted => vec![WindowState::Open, WindowState::Extended],
+            WindowState::Open => vec![WindowState::Extended, WindowState::GracePeriod],
+            WindowState::Extended => vec![WindowState::GracePeriod],
+            WindowState::GracePeriod => vec![WindowState::Extended, WindowState::Expired],
+            WindowState::Expired => vec![WindowState::Extended],
+        }
+    }
+}
+
 /// Represents the current phase within an active voting period.
 ///
 /// Used for future features and analytics.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum VotingPhase {
     /// First third of the voting period
     Early,
@@ -33,12 +67,58 @@ pub enum VotingPhase {
     Late,
 }
 
+impl VotingPhase {
+    /// Returns the `(lo, hi)` fractions of the voting period covered by this phase.
+    pub fn as_fraction_range(&self) -> (f64, f64) {
+        match self {
+            VotingPhase::Early => (0.0, 1.0 / 3.0),
+            VotingPhase::Mid => (1.0 / 3.0, 2.0 / 3.0),
+            VotingPhase::Late => (2.0 / 3.0, 1.0),
+        }
+    }
+}
+
+/// Parameters controlling when and by how much a voting window is extended.
+///
+/// See [`Proposal::extend_window`](crate::models::proposal::Proposal::extend_window)
+/// for how the proximities are interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ExtensionPolicy {
+    /// How many seconds to extend by
+    pub extension_seconds: u64,
+    /// Ratio (0.0-1.0) of the threshold the approval ratio must reach
+    pub threshold_proximity: f64,
+    /// Ratio (0.0-1.0) of the window that must have elapsed
+    pub time_proximity: f64,
+    /// Maximum number of extensions this policy grants (`None` = unlimited)
+    ///
+    /// Applies on top of the window's own `max_extensions`.
+    #[serde(default)]
+    pub max_extensions: Option<u32>,
+}
+
+/// Parameters for extending a window while votes are still arriving quickly.
+///
+/// See [`Proposal::extend_on_velocity`](crate::models::proposal::Proposal::extend_on_velocity).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VelocityPolicy {
+    /// How many seconds to extend by
+    pub extension_seconds: u64,
+    /// Length in seconds of the trailing interval the vote rate is measured over
+    pub window_seconds: u64,
+    /// Votes per second at or above which the window is extended
+    pub min_velocity: f64,
+    /// Ratio (0.0-1.0) of the window that must have elapsed
+    pub time_proximity: f64,
+}
+
 /// Manages the timing and state of a voting window.
 ///
 /// A voting window consists of:
 /// - Main voting period (duration)
 /// - Optional extensions (extended_by)
 /// - Grace period for final evaluation
+/// - Paused intervals, which do not count toward elapsed time
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VotingWindow {
     /// When voting begins
@@ -49,6 +129,21 @@ pub struct VotingWindow {
     pub grace_period: u64,
     /// Additional time added through extensions
     pub extended_by: u64,
+    /// Number of extensions applied so far
+    #[serde(default)]
+    pub extensions: u32,
+    /// Maximum number of extensions allowed (`None` = unlimited)
+    #[serde(default)]
+    pub max_extensions: Option<u32>,
+    /// Total seconds spent in completed pauses
+    #[serde(default)]
+    pub paused_total: u64,
+    /// When the current pause began, if the window is paused
+    #[serde(default)]
+    pub paused_at: Option<DateTime<Utc>>,
+    /// Completed pauses as `(paused_at, resumed_at)` pairs, oldest first
+    #[serde(default)]
+    pub pauses: Vec<(DateTime<Utc>, DateTime<Utc>)>,
 }
 
 impl VotingWindow {
@@ -64,18 +159,145 @@ impl VotingWindow {
             duration,
             grace_period,
             extended_by: 0,
+            extensions: 0,
+            max_extensions: None,
+            paused_total: 0,
+            paused_at: None,
+            pauses: Vec::new(),
         }
     }
 
+    /// Creates a voting window that ends at a fixed deadline.
+    ///
+    /// The duration is derived from the gap between `start_time` and
+    /// `end_time`, and is zero if the deadline is not after the start.
+    ///
+    /// # Arguments
+    /// * `start_time` - When voting begins
+    /// * `end_time` - When voting ends, before any grace period
+    /// * `grace_period` - Grace period after voting ends in seconds
+    pub fn with_end_time(
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        grace_period: u64,
+    ) -> Self {
+        let duration = (end_time - start_time).num_seconds().max(0) as u64;
+        Self::new(start_time, duration, grace_period)
+    }
+
+    /// Returns the deadline for voting, before the grace period.
+    ///
+    /// Includes extensions and completed pauses; a pause still in progress
+    /// will push the deadline back further once it ends.
+    pub fn end_time(&self) -> DateTime<Utc> {
+        let seconds = self.total_duration() + self.paused_total;
+        self.start_time + Duration::seconds(seconds as i64)
+    }
+
+    /// Returns the voting deadline as wall-clock time in the given time zone.
+    ///
+    /// Works with any [`TimeZone`], such as [`chrono::FixedOffset`] or a
+    /// `chrono_tz::Tz` for named zones with daylight saving rules.
+    ///
+    /// # Arguments
+    /// * `tz` - Time zone to express the deadline in
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono::{FixedOffset, TimeZone, Utc};
+    /// use verdyce_core::window::VotingWindow;
+    ///
+    /// let start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+    /// let window = VotingWindow::new(start, 3600, 30);
+    /// let ist = FixedOffset::east_opt(5 * 3600 + 1800).unwrap();
+    /// assert_eq!(window.end_time_in_tz(&ist).to_rfc3339(), "2025-01-01T18:30:00+05:30");
+    /// ```
+    pub fn end_time_in_tz<Tz: TimeZone>(&self, tz: &Tz) -> DateTime<Tz> {
+        self.end_time().with_timezone(tz)
+    }
+
+    /// Limits how many times the window may be extended.
+    ///
+    /// # Arguments
+    /// * `max_extensions` - Maximum number of extensions
+    pub fn with_max_extensions(mut self, max_extensions: u32) -> Self {
+        self.max_extensions = Some(max_extensions);
+        self
+    }
+
+    /// Returns `true` if another extension is allowed under `max_extensions`.
+    pub fn can_extend(&self) -> bool {
+        self.max_extensions.is_none_or(|max| self.extensions < max)
+    }
+
     /// Calculates how much time has elapsed since voting started.
     ///
+    /// Time spent paused before `now`, including an ongoing pause, is not
+    /// counted; pauses that began after `now` don't affect the result. This
+    /// makes it suitable for placing a past vote's timestamp on the voting
+    /// clock.
+    ///
     /// # Arguments
     /// * `now` - Current timestamp
     ///
     /// # Returns
     /// Elapsed time in seconds (0 if voting hasn't started yet)
     pub fn elapsed(&self, now: DateTime<Utc>) -> u64 {
-        (now - self.start_time).num_seconds().max(0) as u64
+        let wall_clock = (now - self.start_time).num_seconds().max(0) as u64;
+        wall_clock.saturating_sub(self.paused_before(now))
+    }
+
+    /// Returns how many seconds the window spent paused before `now`.
+    fn paused_before(&self, now: DateTime<Utc>) -> u64 {
+        let seconds =
+            |from: DateTime<Utc>, to: DateTime<Utc>| (to - from).num_seconds().max(0) as u64;
+        let recorded: u64 = self
+            .pauses
+            .iter()
+            .map(|(from, to)| seconds(*from, *to))
+            .sum();
+        // Windows saved before individual pauses were kept only have a total
+        let mut paused = self.paused_total.saturating_sub(recorded);
+        for (from, to) in &self.pauses {
+            paused += seconds(*from, (*to).min(now));
+        }
+        if let Some(paused_at) = self.paused_at {
+            paused += seconds(paused_at, now);
+        }
+        paused
+    }
+
+    /// Freezes the voting clock, e.g. for a legal hold.
+    ///
+    /// Votes are still accepted while the window is paused; [`VotingWindow::state`]
+    /// keeps reporting it as open. Such votes are weighted as if cast at the
+    /// moment the clock stopped. Does nothing if the window is already paused.
+    ///
+    /// # Arguments
+    /// * `now` - When the pause begins
+    pub fn pause(&mut self, now: DateTime<Utc>) {
+        if self.paused_at.is_none() {
+            self.paused_at = Some(now);
+        }
+    }
+
+    /// Restarts the voting clock after a pause.
+    ///
+    /// The paused interval is added to `paused_total`. Does nothing if the
+    /// window isn't paused.
+    ///
+    /// # Arguments
+    /// * `now` - When the pause ends
+    pub fn resume(&mut self, now: DateTime<Utc>) {
+        if let Some(paused_at) = self.paused_at.take() {
+            self.paused_total += (now - paused_at).num_seconds().max(0) as u64;
+            self.pauses.push((paused_at, now));
+        }
+    }
+
+    /// Returns `true` if the window is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
     }
 
     /// Returns the total duration including any extensions.
@@ -83,8 +305,43 @@ impl VotingWindow {
         self.duration + self.extended_by
     }
 
+    /// Calculates how long remains until the grace period ends.
+    ///
+    /// Before voting starts this is the full duration plus the grace period.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp
+    ///
+    /// # Returns
+    /// Remaining time in seconds (0 once the window has expired)
+    pub fn remaining(&self, now: DateTime<Utc>) -> u64 {
+        (self.total_duration() + self.grace_period).saturating_sub(self.elapsed(now))
+    }
+
+    /// Calculates what fraction of the voting period has elapsed.
+    ///
+    /// The grace period is not included, so progress reaches 1.0 when the
+    /// main voting period ends.
+    ///
+    /// # Arguments
+    /// * `now` - Current timestamp
+    ///
+    /// # Returns
+    /// Progress between 0.0 and 1.0
+    pub fn progress(&self, now: DateTime<Utc>) -> f64 {
+        let total = self.total_duration();
+        if total == 0 {
+            return 1.0;
+        }
+        (self.elapsed(now) as f64 / total as f64).clamp(0.0, 1.0)
+    }
+
     /// Determines the current state of the voting window.
     ///
+    /// Paused time is excluded, so a window paused before its deadline stays
+    /// open for the remainder of its duration after resuming. A paused window
+    /// reports the state it was in when paused, so it keeps accepting votes.
+    ///
     /// # Arguments
     /// * `now` - Current timestamp
     ///
@@ -110,10 +367,20 @@ impl VotingWindow {
 
     /// Extends the voting window by the specified number of seconds.
     ///
+    /// The extension is refused once `max_extensions` has been reached.
+    ///
     /// # Arguments
     /// * `seconds` - Number of seconds to add to the voting period
-    pub fn extend(&mut self, seconds: u64) {
+    ///
+    /// # Returns
+    /// `true` if the extension was applied
+    pub fn extend(&mut self, seconds: u64) -> bool {
+        if !self.can_extend() {
+            return false;
+        }
         self.extended_by += seconds;
+        self.extensions += 1;
+        true
     }
 
     /// Determines which phase of voting we're currently in.
@@ -0,0 +1,84 @@
+#![cfg(feature = "clock")]
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use verdyce_core::async_engine::AsyncEngine;
+use verdyce_core::decay::DecayModel;
+use verdyce_core::models::proposal::{Proposal, ProposalStatus};
+use verdyce_core::models::vote::{Vote, VoteChoice};
+use verdyce_core::storage::{AsyncProposalStore, InMemoryStore};
+use verdyce_core::threshold::ThresholdModel;
+
+fn sample_proposal() -> Proposal {
+    Proposal::new(
+        "Async".into(),
+        "Persisted asynchronously".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    )
+}
+
+fn yes_vote() -> Vote {
+    Vote {
+        validator_id: Uuid::new_v4(),
+        choice: VoteChoice::Yes,
+        timestamp: Utc::now(),
+        revision: 0,
+        reason: None,
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
+    }
+}
+
+#[tokio::test]
+async fn test_cast_vote_saves_updated_proposal() {
+    let mut engine = AsyncEngine::new(InMemoryStore::new());
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    engine.add_proposal(proposal).await;
+    assert!(engine.store().load(id).await.unwrap().votes().is_empty());
+
+    engine.cast_vote(id, yes_vote()).await.unwrap();
+    assert_eq!(engine.store().load(id).await.unwrap().votes().len(), 1);
+
+    engine
+        .evaluate_all(Utc::now() + Duration::seconds(10))
+        .await;
+    let stored = engine.store().load(id).await.unwrap();
+    assert_eq!(stored.status, ProposalStatus::Accepted);
+}
+
+#[tokio::test]
+async fn test_remove_proposal_deletes_from_store() {
+    let mut engine = AsyncEngine::new(InMemoryStore::new());
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    engine.add_proposal(proposal).await;
+
+    assert!(engine.remove_proposal(id).await.is_some());
+    assert!(engine.store().load(id).await.is_none());
+    assert!(!engine.load(id).await);
+}
+
+#[tokio::test]
+async fn test_async_engine_runs_on_spawned_task() {
+    let mut engine = AsyncEngine::new(InMemoryStore::new());
+    let proposal = sample_proposal();
+    let id = proposal.id;
+
+    let engine = tokio::spawn(async move {
+        engine.add_proposal(proposal).await;
+        engine.cast_vote(id, yes_vote()).await.unwrap();
+        engine
+            .evaluate_all(Utc::now() + Duration::seconds(10))
+            .await;
+        engine
+    })
+    .await
+    .unwrap();
+
+    let stored = engine.store().load(id).await.unwrap();
+    assert_eq!(stored.status, ProposalStatus::Accepted);
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "clock")]
+
+use std::sync::Arc;
+use std::thread;
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use verdyce_core::concurrent::ConcurrentEngine;
+use verdyce_core::decay::DecayModel;
+use verdyce_core::engine::Engine;
+use verdyce_core::models::{
+    proposal::{Proposal, ProposalStatus},
+    vote::{Vote, VoteChoice, VoteError},
+};
+use verdyce_core::threshold::ThresholdModel;
+use verdyce_core::window::WindowState;
+
+fn sample_proposal() -> Proposal {
+    Proposal::new(
+        "Concurrent".into(),
+        "Description".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    )
+}
+
+fn new_vote(choice: VoteChoice) -> Vote {
+    Vote {
+        validator_id: Uuid::new_v4(),
+        choice,
+        timestamp: Utc::now(),
+        revision: 0,
+        reason: None,
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
+    }
+}
+
+#[test]
+fn test_concurrent_votes_are_not_lost() {
+    let engine = Arc::new(ConcurrentEngine::new());
+    let (first, second) = (sample_proposal(), sample_proposal());
+    let ids = [first.id, second.id];
+    engine.add_proposal(first);
+    engine.add_proposal(second);
+
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let engine = Arc::clone(&engine);
+            let id = ids[i % 2];
+            thread::spawn(move || {
+                for _ in 0..50 {
+                    assert!(engine.cast_vote(id, new_vote(VoteChoice::Yes)));
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    for id in ids {
+        assert_eq!(engine.get_proposal(id).unwrap().votes().len(), 200);
+    }
+}
+
+#[test]
+fn test_concurrent_engine_round_trips_through_engine() {
+    let mut engine = Engine::new();
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    engine.add_proposal(proposal);
+
+    let concurrent = ConcurrentEngine::from(engine);
+    assert!(concurrent.cast_vote(id, new_vote(VoteChoice::Yes)));
+    assert!(!concurrent.cast_vote(Uuid::new_v4(), new_vote(VoteChoice::Yes)));
+    concurrent.evaluate_all(Utc::now());
+    assert!(!concurrent.cast_vote(id, new_vote(VoteChoice::No)));
+
+    let engine = concurrent.into_engine();
+    assert_eq!(
+        engine.get_proposal(id).unwrap().status,
+        ProposalStatus::Accepted
+    );
+}
+
+#[test]
+fn test_concurrent_engine_holds_dependent_proposals() {
+    let engine = ConcurrentEngine::new();
+    let start = Utc::now();
+    let at = |secs: i64| start + Duration::seconds(secs);
+    let vote = |secs: i64| Vote {
+        timestamp: at(secs),
+        ..new_vote(VoteChoice::Yes)
+    };
+
+    let mut prerequisite = Proposal::new(
+        "Slow".into(),
+        "Description".into(),
+        2000,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    );
+    prerequisite.voting_window.start_time = start;
+    let mut dependent = sample_proposal().with_dependency(prerequisite.id);
+    dependent.voting_window.start_time = start;
+    let (prerequisite_id, dependent_id) = (prerequisite.id, dependent.id);
+    engine.add_proposal(prerequisite);
+    engine.add_proposal(dependent);
+
+    engine.evaluate_all(at(1));
+    assert_eq!(
+        engine.try_cast_vote(dependent_id, vote(10)),
+        Err(VoteError::DependenciesPending)
+    );
+    assert!(engine.get_proposal(dependent_id).unwrap().dependency_hold);
+
+    // The prerequisite passes after the dependent's original 600s window
+    engine.try_cast_vote(prerequisite_id, vote(700)).unwrap();
+    engine.evaluate_all(at(700));
+    engine.evaluate_all(at(701));
+    let dependent = engine.get_proposal(dependent_id).unwrap();
+    assert_eq!(dependent.status, ProposalStatus::Pending);
+    assert_eq!(dependent.voting_window.state(at(701)), WindowState::Open);
+
+    engine.try_cast_vote(dependent_id, vote(705)).unwrap();
+    engine.evaluate_all(at(706));
+    assert_eq!(
+        engine.get_proposal(dependent_id).unwrap().status,
+        ProposalStatus::Accepted
+    );
+}
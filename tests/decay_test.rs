@@ -1,4 +1,4 @@
-use verdyce_core::decay::{DecayModel, weight_calc};
+use verdyce_core::decay::{DecayError, DecayModel, weight_calc};
 
 #[test]
 fn test_linear_decay() {
@@ -28,3 +28,119 @@ fn test_stepped_decay() {
     assert_eq!(w2, 0.5);
     assert_eq!(w3, 0.1);
 }
+
+#[test]
+fn test_polynomial_one_matches_linear() {
+    let poly = DecayModel::Polynomial(1.0);
+    let epsilon = 0.001;
+    for t in [0, 300, 900, 1500, 1800] {
+        let w_poly = weight_calc(&poly, t, 1800);
+        let w_linear = weight_calc(&DecayModel::Linear, t, 1800);
+        assert!((w_poly - w_linear).abs() < epsilon);
+    }
+}
+
+#[test]
+fn test_polynomial_square_stays_above_linear_early() {
+    let square = DecayModel::Polynomial(2.0);
+    let root = DecayModel::Polynomial(0.5);
+    for t in [100, 400, 800] {
+        let w_linear = weight_calc(&DecayModel::Linear, t, 1800);
+        assert!(weight_calc(&square, t, 1800) > w_linear);
+        assert!(weight_calc(&root, t, 1800) < w_linear);
+    }
+}
+
+#[test]
+fn test_polynomial_rejects_non_positive_exponent() {
+    assert_eq!(
+        DecayModel::Polynomial(0.0).validate(),
+        Err(DecayError::NonPositiveExponent(0.0))
+    );
+    assert_eq!(
+        DecayModel::Polynomial(-1.5).validate(),
+        Err(DecayError::NonPositiveExponent(-1.5))
+    );
+    assert!(DecayModel::Polynomial(2.0).validate().is_ok());
+}
+
+#[test]
+fn test_decay_sample_endpoints_match_weight_calc() {
+    let model = DecayModel::Exponential(0.002);
+    let samples = model.sample(3600, 5);
+
+    assert_eq!(samples.len(), 5);
+    assert_eq!(samples[0], (0, weight_calc(&model, 0, 3600)));
+    assert_eq!(samples[2].0, 1800);
+    assert_eq!(samples[4], (3600, weight_calc(&model, 3600, 3600)));
+}
+
+#[test]
+fn test_decay_sample_with_too_few_points_returns_endpoints() {
+    let samples = DecayModel::Linear.sample(600, 1);
+    let times: Vec<u64> = samples.iter().map(|(t, _)| *t).collect();
+    assert_eq!(times, vec![0, 600]);
+}
+
+#[test]
+fn test_zero_total_gives_full_weight() {
+    for model in [
+        DecayModel::Linear,
+        DecayModel::Stepped,
+        DecayModel::Polynomial(2.0),
+        DecayModel::Exponential(0.1),
+    ] {
+        let w = weight_calc(&model, 10, 0);
+        assert_eq!(w, 1.0, "{model:?}");
+    }
+}
+
+#[test]
+fn test_decay_model_serde_round_trip() {
+    for model in [
+        DecayModel::Linear,
+        DecayModel::Exponential(0.1),
+        DecayModel::Stepped,
+        DecayModel::Polynomial(2.5),
+    ] {
+        let json = serde_json::to_string(&model).unwrap();
+        let restored: DecayModel = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, model, "{json}");
+    }
+    assert_eq!(
+        serde_json::to_string(&DecayModel::Exponential(0.1)).unwrap(),
+        r#"{"Exponential":0.1}"#
+    );
+}
+
+#[test]
+fn test_stepped_n_three_matches_stepped_phases() {
+    let three = DecayModel::SteppedN(3);
+    for total in [100, 1800] {
+        for t in 0..=total + 10 {
+            let stepped = weight_calc(&DecayModel::Stepped, t, total);
+            let expected = if stepped == 0.5 { 0.55 } else { stepped };
+            let w = weight_calc(&three, t, total);
+            assert!((w - expected).abs() < 1e-9, "t={t} total={total}");
+        }
+    }
+}
+
+#[test]
+fn test_stepped_n_five_has_five_levels() {
+    let five = DecayModel::SteppedN(5);
+    let levels: Vec<f64> = [0, 250, 450, 700, 1000]
+        .into_iter()
+        .map(|t| weight_calc(&five, t, 1000))
+        .collect();
+    let expected = [1.0, 0.775, 0.55, 0.325, 0.1];
+    for (w, e) in levels.iter().zip(expected) {
+        assert!((w - e).abs() < 1e-9, "{levels:?}");
+    }
+
+    assert_eq!(
+        DecayModel::SteppedN(1).validate(),
+        Err(DecayError::TooFewSteps(1))
+    );
+    assert_eq!(weight_calc(&DecayModel::SteppedN(0), 600, 1000), 0.1);
+}
@@ -1,12 +1,15 @@
+#![cfg(feature = "clock")]
+
 use chrono::{Duration, Utc};
 use uuid::Uuid;
 use verdyce_core::decay::DecayModel;
-use verdyce_core::engine::Engine;
+use verdyce_core::engine::{Engine, EngineSummary};
 use verdyce_core::models::{
-    proposal::{Proposal, ProposalStatus},
-    vote::{Vote, VoteChoice},
+    proposal::{Proposal, ProposalStatus, RejectionReason},
+    vote::{Vote, VoteChoice, VoteError},
 };
 use verdyce_core::threshold::ThresholdModel;
+use verdyce_core::window::{ExtensionPolicy, WindowState};
 
 fn sample_proposal() -> Proposal {
     Proposal::new(
@@ -25,6 +28,9 @@ fn sample_vote(choice: VoteChoice, seconds_ago: i64, revision: u64) -> Vote {
         timestamp: Utc::now() - Duration::seconds(seconds_ago),
         revision,
         reason: Some("test".into()),
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
     }
 }
 
@@ -48,11 +54,11 @@ fn test_cast_vote_success() {
     let id = proposal.id;
     engine.add_proposal(proposal);
 
-    let vote = sample_vote(VoteChoice::Yes, 10, 0);
+    let vote = sample_vote(VoteChoice::Yes, 0, 0);
     let success = engine.cast_vote(id, vote);
 
     assert!(success);
-    assert_eq!(engine.get_proposal(id).unwrap().votes.len(), 1);
+    assert_eq!(engine.get_proposal(id).unwrap().votes().len(), 1);
 }
 
 #[test]
@@ -115,6 +121,9 @@ fn test_maybe_extend_all() {
         timestamp: Utc::now(),
         revision: 0,
         reason: None,
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
     });
 
     let id = proposal.id;
@@ -124,3 +133,762 @@ fn test_maybe_extend_all() {
     let proposal = engine.get_proposal(id).unwrap();
     assert_eq!(proposal.voting_window.extended_by, 30);
 }
+
+#[test]
+fn test_get_proposals_by_tag() {
+    let mut engine = Engine::new();
+    let treasury = sample_proposal().with_tags(vec!["Treasury".into(), "urgent".into()]);
+    let protocol = sample_proposal().with_tags(vec!["protocol".into(), "URGENT".into()]);
+    let community = sample_proposal().with_tags(vec!["community".into()]);
+    let (treasury_id, protocol_id) = (treasury.id, protocol.id);
+
+    engine.add_proposal(treasury);
+    engine.add_proposal(protocol);
+    engine.add_proposal(community);
+
+    let urgent: Vec<Uuid> = engine
+        .get_proposals_by_tag("urgent")
+        .iter()
+        .map(|p| p.id)
+        .collect();
+    assert_eq!(urgent, vec![treasury_id, protocol_id]);
+
+    let treasury_only = engine.get_proposals_by_tag("treasury");
+    assert_eq!(treasury_only.len(), 1);
+    assert_eq!(treasury_only[0].id, treasury_id);
+
+    assert!(engine.get_proposals_by_tag("missing").is_empty());
+}
+
+#[test]
+fn test_remove_proposal() {
+    let mut engine = Engine::new();
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    engine.add_proposal(proposal);
+
+    let removed = engine.remove_proposal(id);
+    assert_eq!(removed.map(|p| p.id), Some(id));
+    assert!(engine.get_proposal(id).is_none());
+    assert!(engine.remove_proposal(id).is_none());
+}
+
+#[test]
+fn test_archive_finalized_leaves_pending() {
+    let mut engine = Engine::new();
+    let pending = sample_proposal();
+    let pending_id = pending.id;
+    engine.add_proposal(pending);
+
+    for status in [
+        ProposalStatus::Accepted,
+        ProposalStatus::Rejected,
+        ProposalStatus::Expired,
+    ] {
+        let mut proposal = sample_proposal();
+        proposal.status = status;
+        engine.add_proposal(proposal);
+    }
+
+    let archived = engine.archive_finalized();
+
+    assert_eq!(archived.len(), 3);
+    assert!(archived.iter().all(|p| p.status != ProposalStatus::Pending));
+    assert_eq!(engine.proposals.len(), 1);
+    assert_eq!(engine.get_active_proposals()[0].id, pending_id);
+    assert!(engine.get_finalized().is_empty());
+}
+
+#[test]
+fn test_get_and_count_by_status() {
+    let mut engine = Engine::new();
+    for status in [
+        ProposalStatus::Pending,
+        ProposalStatus::Accepted,
+        ProposalStatus::Accepted,
+        ProposalStatus::Rejected,
+    ] {
+        let mut proposal = sample_proposal();
+        proposal.status = status;
+        engine.add_proposal(proposal);
+    }
+
+    assert_eq!(engine.get_by_status(ProposalStatus::Accepted).len(), 2);
+    assert_eq!(engine.get_by_status(ProposalStatus::Rejected).len(), 1);
+    assert!(engine.get_by_status(ProposalStatus::Expired).is_empty());
+
+    let counts = engine.count_by_status();
+    assert_eq!(counts[&ProposalStatus::Pending], 1);
+    assert_eq!(counts[&ProposalStatus::Accepted], 2);
+    assert_eq!(counts[&ProposalStatus::Rejected], 1);
+    assert_eq!(counts[&ProposalStatus::Expired], 0);
+    assert_eq!(counts.len(), ProposalStatus::ALL.len());
+}
+
+#[test]
+fn test_engine_json_round_trip() {
+    let mut engine = Engine::new();
+    for votes in 0..3 {
+        let mut proposal = sample_proposal();
+        for _ in 0..votes {
+            proposal.add_vote(sample_vote(VoteChoice::No, 5, 0));
+        }
+        engine.add_proposal(proposal);
+    }
+    engine.proposals[0].status = ProposalStatus::Accepted;
+
+    let json = engine.to_json().unwrap();
+    let restored = Engine::from_json(&json).unwrap();
+
+    assert_eq!(restored.proposals.len(), engine.proposals.len());
+    for (original, copy) in engine.proposals.iter().zip(&restored.proposals) {
+        assert_eq!(copy.id, original.id);
+        assert_eq!(copy.status, original.status);
+        assert_eq!(copy.votes().len(), original.votes().len());
+    }
+    assert!(restored.store().is_none());
+    assert!(Engine::from_json("not json").is_err());
+}
+
+#[test]
+fn test_status_change_listener_fires_once() {
+    use std::sync::{Arc, Mutex};
+
+    let mut engine = Engine::new();
+    let mut proposal = sample_proposal();
+    let id = proposal.id;
+    proposal.add_vote(sample_vote(VoteChoice::Yes, 5, 0));
+    engine.add_proposal(proposal);
+    engine.add_proposal(sample_proposal());
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&events);
+    engine.on_status_change(Box::new(move |proposal, from, to| {
+        recorded.lock().unwrap().push((proposal.id, from, to));
+    }));
+
+    engine.evaluate_all(Utc::now());
+    engine.evaluate_all(Utc::now());
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![(id, ProposalStatus::Pending, ProposalStatus::Accepted)]
+    );
+}
+
+#[test]
+fn test_tick_reports_status_changes() {
+    let mut engine = Engine::new();
+    let mut accepted = sample_proposal();
+    accepted.add_vote(sample_vote(VoteChoice::Yes, 5, 0));
+    let accepted_id = accepted.id;
+    let expiring = sample_proposal();
+    let expiring_id = expiring.id;
+    engine.add_proposal(accepted);
+    engine.add_proposal(expiring);
+
+    let start = Utc::now();
+    assert_eq!(engine.tick(start), vec![accepted_id]);
+    assert!(engine.tick(start + Duration::seconds(30)).is_empty());
+    assert_eq!(
+        engine.tick(start + Duration::seconds(120)),
+        vec![expiring_id]
+    );
+
+    assert_eq!(
+        engine.get_proposal(expiring_id).unwrap().status,
+        ProposalStatus::Expired
+    );
+}
+
+#[test]
+fn test_tick_applies_extension_policy() {
+    let mut engine = Engine::new();
+    let mut proposal = Proposal::new(
+        "Test".into(),
+        "Should Extend".into(),
+        100,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.9),
+    );
+    proposal.voting_window.start_time = Utc::now() - Duration::seconds(91);
+    proposal.add_vote(sample_vote(VoteChoice::Yes, 0, 0));
+    proposal.add_vote(sample_vote(VoteChoice::Yes, 0, 0));
+    proposal.add_vote(sample_vote(VoteChoice::No, 0, 0));
+    let id = proposal.id;
+    engine.add_proposal(proposal);
+
+    assert!(engine.tick(Utc::now()).is_empty());
+    assert_eq!(
+        engine.get_proposal(id).unwrap().voting_window.extended_by,
+        0
+    );
+
+    engine.set_extension_policy(Some(ExtensionPolicy {
+        extension_seconds: 30,
+        threshold_proximity: 0.7,
+        time_proximity: 0.9,
+        max_extensions: None,
+    }));
+    assert!(engine.tick(Utc::now()).is_empty());
+    assert_eq!(
+        engine.get_proposal(id).unwrap().voting_window.extended_by,
+        30
+    );
+}
+
+#[test]
+fn test_validator_registry_restricts_votes() {
+    let mut engine = Engine::new();
+    let mut proposal = sample_proposal();
+    let registered = sample_vote(VoteChoice::Yes, 0, 0);
+    proposal.register_validator(registered.validator_id);
+    let id = proposal.id;
+    engine.add_proposal(proposal);
+
+    assert_eq!(engine.try_cast_vote(id, registered), Ok(()));
+    assert_eq!(
+        engine.try_cast_vote(id, sample_vote(VoteChoice::No, 0, 0)),
+        Err(VoteError::NotEligible)
+    );
+    assert_eq!(engine.get_proposal(id).unwrap().votes().len(), 1);
+}
+
+#[test]
+fn test_empty_registry_allows_anyone() {
+    let mut engine = Engine::new();
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    engine.add_proposal(proposal);
+
+    assert_eq!(
+        engine.try_cast_vote(id, sample_vote(VoteChoice::No, 0, 0)),
+        Ok(())
+    );
+    assert_eq!(
+        engine.try_cast_vote(Uuid::new_v4(), sample_vote(VoteChoice::No, 0, 0)),
+        Err(VoteError::ProposalNotFound)
+    );
+
+    engine.proposals[0].status = ProposalStatus::Rejected;
+    assert_eq!(
+        engine.try_cast_vote(id, sample_vote(VoteChoice::No, 0, 0)),
+        Err(VoteError::ProposalClosed)
+    );
+}
+
+#[test]
+fn test_get_finalized_includes_cancelled() {
+    let mut engine = Engine::new();
+    let mut cancelled = sample_proposal();
+    cancelled.cancel();
+    let id = cancelled.id;
+    engine.add_proposal(cancelled);
+    engine.add_proposal(sample_proposal());
+
+    let finalized = engine.get_finalized();
+    assert_eq!(finalized.len(), 1);
+    assert_eq!(finalized[0].id, id);
+    assert!(!engine.cast_vote(id, sample_vote(VoteChoice::Yes, 0, 0)));
+}
+
+#[test]
+fn test_summary_of_mixed_engine() {
+    let mut engine = Engine::new();
+
+    let mut closing = sample_proposal();
+    closing.add_vote(sample_vote(VoteChoice::Yes, 0, 0));
+    closing.add_vote(sample_vote(VoteChoice::No, 0, 0));
+    let mut long_running = Proposal::new(
+        "Long".into(),
+        "Description".into(),
+        3600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.5, 0.0),
+    );
+    long_running.add_vote(sample_vote(VoteChoice::Yes, 0, 0));
+    let mut cancelled = sample_proposal();
+    cancelled.cancel();
+
+    engine.add_proposal(closing);
+    engine.add_proposal(long_running);
+    engine.add_proposal(cancelled);
+
+    let summary = engine.summary(Utc::now());
+    assert_eq!(summary.counts_by_status[&ProposalStatus::Pending], 2);
+    assert_eq!(summary.counts_by_status[&ProposalStatus::Cancelled], 1);
+    assert_eq!(summary.counts_by_status[&ProposalStatus::Accepted], 0);
+    assert_eq!(summary.nearing_expiry, 1);
+    assert!((summary.average_pending_ratio - 0.75).abs() < 1e-9);
+    assert_eq!(summary.total_votes, 3);
+}
+
+#[test]
+fn test_summary_serializes_for_metrics() {
+    let summary = Engine::new().summary(Utc::now());
+    assert_eq!(summary.average_pending_ratio, 0.0);
+
+    let json = serde_json::to_string(&summary).unwrap();
+    let restored: EngineSummary = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, summary);
+}
+
+#[test]
+fn test_try_cast_vote_checks_vote_timestamp() {
+    let mut engine = Engine::new();
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    let start = proposal.voting_window.start_time;
+    engine.add_proposal(proposal);
+
+    let mut vote = sample_vote(VoteChoice::Yes, 0, 0);
+    vote.timestamp = start - Duration::seconds(10);
+    assert_eq!(
+        engine.try_cast_vote(id, vote.clone()),
+        Err(VoteError::BeforeWindowStart)
+    );
+    vote.timestamp = start + Duration::seconds(60 + 30 + 1);
+    assert_eq!(
+        engine.try_cast_vote(id, vote.clone()),
+        Err(VoteError::AfterGracePeriod)
+    );
+    assert!(engine.get_proposal(id).unwrap().votes().is_empty());
+
+    vote.timestamp = start + Duration::seconds(10);
+    assert_eq!(engine.try_cast_vote(id, vote), Ok(()));
+}
+
+#[test]
+fn test_cast_vote_at_accepts_in_window_vote() {
+    let mut engine = Engine::new();
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    let start = proposal.voting_window.start_time;
+    engine.add_proposal(proposal);
+
+    let mut vote = sample_vote(VoteChoice::Yes, 0, 0);
+    vote.timestamp = start + Duration::seconds(10);
+    assert_eq!(
+        engine.cast_vote_at(id, vote, start + Duration::seconds(10)),
+        Ok(())
+    );
+    assert_eq!(engine.get_proposal(id).unwrap().votes().len(), 1);
+}
+
+#[test]
+fn test_cast_vote_at_rejects_post_expiry_vote_before_evaluation() {
+    let mut engine = Engine::new();
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    let start = proposal.voting_window.start_time;
+    engine.add_proposal(proposal);
+
+    let late = start + Duration::seconds(60 + 30 + 1);
+    let mut vote = sample_vote(VoteChoice::Yes, 0, 0);
+    vote.timestamp = late;
+    assert_eq!(
+        engine.cast_vote_at(id, vote.clone(), late),
+        Err(VoteError::AfterGracePeriod)
+    );
+
+    vote.timestamp = start - Duration::seconds(1);
+    assert_eq!(
+        engine.cast_vote_at(id, vote, start),
+        Err(VoteError::BeforeWindowStart)
+    );
+    let proposal = engine.get_proposal(id).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+    assert!(proposal.votes().is_empty());
+}
+
+#[test]
+fn test_dependent_proposal_waits_for_prerequisite() {
+    let mut engine = Engine::new();
+    let stage_one = sample_proposal();
+    let stage_one_id = stage_one.id;
+    let mut stage_two = sample_proposal().with_dependency(stage_one_id);
+    stage_two.add_vote(sample_vote(VoteChoice::Yes, 0, 0));
+    let stage_two_id = stage_two.id;
+    engine.add_proposal(stage_two);
+    engine.add_proposal(stage_one);
+
+    assert_eq!(engine.activatable(), vec![stage_one_id]);
+    engine.evaluate_all(Utc::now());
+    assert_eq!(
+        engine.get_proposal(stage_two_id).unwrap().status,
+        ProposalStatus::Pending
+    );
+
+    engine.cast_vote(stage_one_id, sample_vote(VoteChoice::Yes, 0, 0));
+    engine.evaluate_all(Utc::now());
+    assert_eq!(
+        engine.get_proposal(stage_one_id).unwrap().status,
+        ProposalStatus::Accepted
+    );
+    assert_eq!(
+        engine.get_proposal(stage_two_id).unwrap().status,
+        ProposalStatus::Pending
+    );
+    assert_eq!(engine.activatable(), vec![stage_two_id]);
+
+    engine.evaluate_all(Utc::now());
+    assert_eq!(
+        engine.get_proposal(stage_two_id).unwrap().status,
+        ProposalStatus::Accepted
+    );
+}
+
+#[test]
+fn test_missing_dependency_is_never_satisfied() {
+    let mut engine = Engine::new();
+    let mut orphan = sample_proposal().with_dependency(Uuid::new_v4());
+    orphan.add_vote(sample_vote(VoteChoice::Yes, 0, 0));
+    let id = orphan.id;
+    engine.add_proposal(orphan);
+
+    engine.evaluate_all(Utc::now());
+    assert!(engine.activatable().is_empty());
+    assert_eq!(
+        engine.get_proposal(id).unwrap().status,
+        ProposalStatus::Pending
+    );
+}
+
+#[test]
+fn test_maybe_extend_all_prefers_proposal_policies() {
+    let policy_proposal = |threshold_proximity: f64| {
+        let mut proposal = Proposal::new(
+            "Test".into(),
+            "Per-proposal policy".into(),
+            100,
+            DecayModel::Linear,
+            ThresholdModel::Linear(0.0, 0.9),
+        )
+        .with_extension_policy(ExtensionPolicy {
+            extension_seconds: 20,
+            threshold_proximity,
+            time_proximity: 0.5,
+            max_extensions: Some(1),
+        });
+        proposal.voting_window.start_time = Utc::now() - Duration::seconds(91);
+        proposal.add_vote(sample_vote(VoteChoice::Yes, 0, 0));
+        proposal.add_vote(sample_vote(VoteChoice::No, 0, 0));
+        proposal
+    };
+    let mut engine = Engine::new();
+    let eager = policy_proposal(0.5);
+    let strict = policy_proposal(0.9);
+    let (eager_id, strict_id) = (eager.id, strict.id);
+    engine.add_proposal(eager);
+    engine.add_proposal(strict);
+
+    engine.maybe_extend_all(Utc::now(), 300, 1.0, 1.0);
+    engine.maybe_extend_all(Utc::now(), 300, 1.0, 1.0);
+
+    let extended_by = |id| engine.get_proposal(id).unwrap().voting_window.extended_by;
+    assert_eq!(extended_by(eager_id), 20);
+    assert_eq!(extended_by(strict_id), 0);
+}
+
+#[test]
+fn test_update_and_targeted_helpers() {
+    let mut engine = Engine::new();
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    engine.add_proposal(proposal);
+
+    engine.update(id, |p| p.tags = vec!["treasury".into()]);
+    assert!(engine.get_proposal(id).unwrap().has_tag("treasury"));
+
+    assert!(engine.extend(id, 30));
+    assert_eq!(
+        engine.get_proposal(id).unwrap().voting_window.extended_by,
+        30
+    );
+
+    assert!(engine.cancel(id));
+    assert_eq!(
+        engine.get_proposal(id).unwrap().status,
+        ProposalStatus::Cancelled
+    );
+    assert!(!engine.cancel(id));
+    assert!(!engine.extend(id, 30));
+}
+
+#[test]
+fn test_update_unknown_id() {
+    let mut engine = Engine::new();
+    engine.add_proposal(sample_proposal());
+    let missing = Uuid::new_v4();
+
+    assert!(engine.update(missing, |_| ()).is_none());
+    assert!(!engine.extend(missing, 30));
+    assert!(!engine.cancel(missing));
+}
+
+#[test]
+fn test_update_notifies_listeners_and_saves() {
+    use std::sync::{Arc, Mutex};
+    use verdyce_core::storage::InMemoryStore;
+
+    let mut engine = Engine::with_store(Box::new(InMemoryStore::new()));
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    engine.add_proposal(proposal);
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&events);
+    engine.on_status_change(Box::new(move |proposal, from, to| {
+        recorded.lock().unwrap().push((proposal.id, from, to));
+    }));
+
+    assert_eq!(engine.update(id, |p| p.cancel_at(Utc::now())), Some(true));
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![(id, ProposalStatus::Pending, ProposalStatus::Cancelled)]
+    );
+    let stored = engine.store().unwrap().load(id).unwrap();
+    assert_eq!(stored.status, ProposalStatus::Cancelled);
+}
+
+#[test]
+fn test_scheduled_proposal_refuses_votes_until_start() {
+    let now = Utc::now();
+    let opens_at = now + Duration::seconds(3600);
+    let proposal = Proposal::builder()
+        .title("Scheduled")
+        .created_at(now)
+        .start_time(opens_at)
+        .duration(600)
+        .decay_model(DecayModel::Linear)
+        .threshold_model(ThresholdModel::Linear(0.0, 0.5))
+        .build()
+        .unwrap();
+    let id = proposal.id;
+    assert_eq!(proposal.created_at, now);
+    assert_eq!(proposal.voting_window.state(now), WindowState::NotStarted);
+
+    let mut engine = Engine::new();
+    engine.add_proposal(proposal);
+    assert_eq!(
+        engine.try_cast_vote(id, sample_vote(VoteChoice::Yes, 0, 0)),
+        Err(VoteError::BeforeWindowStart)
+    );
+
+    let mut early = sample_vote(VoteChoice::Yes, 0, 0);
+    early.timestamp = opens_at - Duration::seconds(1);
+    assert_eq!(
+        engine.cast_vote_at(id, early, now),
+        Err(VoteError::BeforeWindowStart)
+    );
+
+    let mut opened = sample_vote(VoteChoice::Yes, 0, 0);
+    opened.timestamp = opens_at + Duration::seconds(5);
+    assert_eq!(
+        engine.cast_vote_at(id, opened, opens_at + Duration::seconds(5)),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_scheduled_proposal_stays_pending_before_start() {
+    let now = Utc::now();
+    let opens_at = now + Duration::seconds(3600);
+    let mut proposal = Proposal::builder()
+        .title("Scheduled")
+        .start_time(opens_at)
+        .duration(600)
+        .decay_model(DecayModel::Linear)
+        .threshold_model(ThresholdModel::Linear(0.0, 0.5))
+        .build()
+        .unwrap();
+    let mut vote = sample_vote(VoteChoice::Yes, 0, 0);
+    vote.timestamp = opens_at;
+    proposal.add_vote(vote);
+
+    proposal.evaluate(now);
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+
+    proposal.evaluate(opens_at + Duration::seconds(10));
+    assert_eq!(proposal.status, ProposalStatus::Accepted);
+}
+
+#[test]
+fn test_cast_vote_at_refuses_future_timestamps() {
+    let mut engine = Engine::new();
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    engine.add_proposal(proposal);
+    let now = Utc::now();
+
+    let mut future = sample_vote(VoteChoice::Yes, 0, 0);
+    future.timestamp = now + Duration::seconds(5);
+    assert_eq!(
+        engine.cast_vote_at(id, future.clone(), now),
+        Err(VoteError::FutureTimestamp)
+    );
+
+    engine.set_max_clock_skew(10);
+    assert_eq!(engine.max_clock_skew(), 10);
+    assert_eq!(engine.cast_vote_at(id, future, now), Ok(()));
+
+    let mut too_far = sample_vote(VoteChoice::Yes, 0, 0);
+    too_far.timestamp = now + Duration::seconds(11);
+    assert_eq!(
+        engine.cast_vote_at(id, too_far, now),
+        Err(VoteError::FutureTimestamp)
+    );
+}
+
+#[test]
+fn test_cast_vote_at_clamps_backdated_timestamps() {
+    let mut engine = Engine::new();
+    let proposal = sample_proposal();
+    let id = proposal.id;
+    let start = proposal.voting_window.start_time;
+    engine.add_proposal(proposal);
+
+    let mut backdated = sample_vote(VoteChoice::Yes, 0, 0);
+    backdated.timestamp = start + Duration::seconds(1);
+    assert_eq!(
+        engine.cast_vote_at(id, backdated.clone(), start + Duration::seconds(95)),
+        Err(VoteError::AfterGracePeriod)
+    );
+    assert!(engine.get_proposal(id).unwrap().votes().is_empty());
+
+    let received = start + Duration::seconds(70);
+    assert_eq!(engine.cast_vote_at(id, backdated, received), Ok(()));
+    let stored = &engine.get_proposal(id).unwrap().votes()[0];
+    assert_eq!(stored.timestamp, received);
+    assert!(stored.cast_during_grace);
+}
+
+#[test]
+fn test_quorum_failure_is_distinct_from_rejection() {
+    let mut engine = Engine::new();
+    let ended = |quorum: usize| {
+        let mut proposal = sample_proposal().with_quorum(quorum);
+        proposal.voting_window.start_time = Utc::now() - Duration::seconds(70);
+        proposal.add_vote(sample_vote(VoteChoice::No, 65, 0));
+        proposal.add_vote(sample_vote(VoteChoice::No, 65, 0));
+        proposal
+    };
+    let inquorate = ended(3);
+    let rejected = ended(2);
+    let (inquorate_id, rejected_id) = (inquorate.id, rejected.id);
+    engine.add_proposal(inquorate);
+    engine.add_proposal(rejected);
+
+    engine.evaluate_all(Utc::now());
+
+    let inquorate = engine.get_proposal(inquorate_id).unwrap();
+    assert_eq!(inquorate.status, ProposalStatus::NoQuorum);
+    assert_eq!(
+        inquorate.rejection_reason,
+        Some(RejectionReason::QuorumNotMet)
+    );
+    let rejected = engine.get_proposal(rejected_id).unwrap();
+    assert_eq!(rejected.status, ProposalStatus::Rejected);
+    assert_eq!(
+        rejected.rejection_reason,
+        Some(RejectionReason::ThresholdNotMet)
+    );
+    assert_eq!(engine.get_finalized().len(), 2);
+}
+
+#[test]
+fn test_find_filters_by_vote_count() {
+    let mut engine = Engine::new();
+    let mut busy = sample_proposal();
+    for _ in 0..3 {
+        busy.add_vote(sample_vote(VoteChoice::Yes, 0, 0));
+    }
+    let busy_id = busy.id;
+    engine.add_proposal(busy);
+    engine.add_proposal(sample_proposal());
+
+    let found = engine.find(|p| p.status == ProposalStatus::Pending && p.votes().len() > 2);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id, busy_id);
+    assert!(engine.find(|p| p.votes().len() > 3).is_empty());
+    assert_eq!(engine.find(|_| true).len(), 2);
+}
+
+#[test]
+fn test_find_one_by_creation_time() {
+    let mut engine = Engine::new();
+    let mut old = sample_proposal();
+    old.created_at = Utc::now() - Duration::hours(3);
+    let recent = sample_proposal();
+    let recent_id = recent.id;
+    engine.add_proposal(old);
+    engine.add_proposal(recent);
+
+    let hour_ago = Utc::now() - Duration::hours(1);
+    let found = engine.find_one(|p| p.created_at > hour_ago).unwrap();
+    assert_eq!(found.id, recent_id);
+    assert_eq!(engine.find(|p| p.created_at > hour_ago).len(), 1);
+    assert!(
+        engine
+            .find_one(|p| p.created_at < hour_ago - Duration::hours(5))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_dependent_window_waits_for_slow_prerequisite() {
+    let mut engine = Engine::new();
+    let start = Utc::now();
+    let at = |secs: i64| start + Duration::seconds(secs);
+    let vote = |secs: i64| Vote {
+        timestamp: at(secs),
+        ..sample_vote(VoteChoice::Yes, 0, 0)
+    };
+
+    let mut prerequisite = Proposal::new(
+        "Slow".into(),
+        "Description".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    );
+    prerequisite.voting_window.start_time = start;
+    let mut dependent = sample_proposal().with_dependency(prerequisite.id);
+    dependent.voting_window.start_time = start;
+    let (prerequisite_id, dependent_id) = (prerequisite.id, dependent.id);
+    engine.add_proposal(prerequisite);
+    engine.add_proposal(dependent);
+
+    engine.evaluate_all(at(1));
+    assert_eq!(
+        engine.cast_vote_at(dependent_id, vote(10), at(10)),
+        Err(VoteError::DependenciesPending)
+    );
+    assert_eq!(
+        engine.try_cast_vote(dependent_id, vote(10)),
+        Err(VoteError::DependenciesPending)
+    );
+
+    // The prerequisite passes well after the dependent's original 60s window
+    engine
+        .cast_vote_at(prerequisite_id, vote(120), at(120))
+        .unwrap();
+    engine.evaluate_all(at(120));
+    assert_eq!(
+        engine.get_proposal(prerequisite_id).unwrap().status,
+        ProposalStatus::Accepted
+    );
+
+    engine.evaluate_all(at(121));
+    let dependent = engine.get_proposal(dependent_id).unwrap();
+    assert_eq!(dependent.status, ProposalStatus::Pending);
+    assert_eq!(dependent.voting_window.state(at(121)), WindowState::Open);
+
+    engine
+        .cast_vote_at(dependent_id, vote(125), at(125))
+        .unwrap();
+    engine.evaluate_all(at(126));
+    assert_eq!(
+        engine.get_proposal(dependent_id).unwrap().status,
+        ProposalStatus::Accepted
+    );
+}
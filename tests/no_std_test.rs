@@ -0,0 +1,32 @@
+//! Exercises the decay and threshold math on its own, as available without
+//! `std`. Run against the `no_std` build with
+//! `cargo test --no-default-features --test no_std_test`.
+
+use verdyce_core::decay::{DecayModel, weight_calc};
+use verdyce_core::threshold::{ThresholdModel, threshold_calc, threshold_slope};
+
+#[test]
+fn test_decay_math_without_std() {
+    let expected = (-0.001f64 * 300.0).exp();
+    let w = weight_calc(&DecayModel::Exponential(0.001), 300, 1800);
+    assert!((w - expected).abs() < 1e-12);
+
+    let w = weight_calc(&DecayModel::Polynomial(2.0), 900, 1800);
+    assert!((w - 0.75).abs() < 1e-12);
+
+    assert_eq!(weight_calc(&DecayModel::SteppedN(4), 1800, 1800), 0.1);
+}
+
+#[test]
+fn test_threshold_math_without_std() {
+    let model = ThresholdModel::Exponential(0.001, 0.5);
+    let expected = 0.5 + 0.5 * (1.0 - (-0.001f64 * 600.0).exp());
+    assert!((threshold_calc(&model, 600, 3600) - expected).abs() < 1e-12);
+
+    let sigmoid = ThresholdModel::Sigmoid(10.0, 0.4);
+    assert!((threshold_calc(&sigmoid, 1800, 3600) - 0.7).abs() < 1e-12);
+    assert!(threshold_slope(&sigmoid, 1800, 3600) > 0.0);
+
+    let samples = model.sample(3600, 5);
+    assert_eq!(samples.len(), 5);
+}
@@ -1,7 +1,12 @@
+#![cfg(feature = "clock")]
+
 use verdyce_core::decay::DecayModel;
 use verdyce_core::models::proposal::*;
 use verdyce_core::models::vote::*;
 use verdyce_core::threshold::ThresholdModel;
+use verdyce_core::window::{
+    DEFAULT_GRACE_PERIOD, ExtensionPolicy, VelocityPolicy, VotingWindow, WindowState,
+};
 
 use chrono::{Duration, Utc};
 use uuid::Uuid;
@@ -13,6 +18,9 @@ fn dummy_vote(choice: VoteChoice, timestamp_offset_secs: i64, revision: u64) ->
         timestamp: Utc::now() - Duration::seconds(timestamp_offset_secs),
         revision,
         reason: None,
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
     }
 }
 
@@ -96,8 +104,2068 @@ fn test_extend_when_near_threshold_and_time() {
         timestamp: now,
         revision: 0,
         reason: None,
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
     });
 
     proposal.extend_window(now, 30, 0.9, 0.9);
     assert_eq!(proposal.voting_window.extended_by, 30);
 }
+
+#[test]
+fn test_low_participation_blocks_acceptance() {
+    let now = Utc::now();
+    let model = ThresholdModel::TimeAndParticipation {
+        base: Box::new(ThresholdModel::Linear(0.0, 0.5)),
+        participation_weight: 0.5,
+    };
+
+    let mut sparse = Proposal::new(
+        "Sparse".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        model.clone(),
+    );
+    sparse.max_validators = Some(10);
+    sparse.add_vote(dummy_vote(VoteChoice::Yes, 0, 0));
+    sparse.add_vote(dummy_vote(VoteChoice::Yes, 0, 0));
+    sparse.add_vote(dummy_vote(VoteChoice::No, 0, 0));
+    assert!((sparse.participation() - 0.3).abs() < 0.001);
+
+    let mut full = Proposal::new("Full".into(), "Desc".into(), 600, DecayModel::Linear, model);
+    full.max_validators = Some(3);
+    for vote in sparse.votes() {
+        full.add_vote(vote.clone());
+    }
+
+    sparse.evaluate(now + Duration::seconds(60));
+    full.evaluate(now + Duration::seconds(60));
+
+    assert_eq!(sparse.status, ProposalStatus::Pending);
+    assert_eq!(full.status, ProposalStatus::Accepted);
+}
+
+#[test]
+fn test_try_new_rejects_invalid_models() {
+    let bad_threshold = Proposal::try_new(
+        "Bad".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Sigmoid(0.0, 0.5),
+    );
+    assert_eq!(
+        bad_threshold.unwrap_err(),
+        ProposalError::Threshold(verdyce_core::threshold::ThresholdError::ZeroRate)
+    );
+
+    let bad_decay = Proposal::try_new(
+        "Bad".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Polynomial(0.0),
+        ThresholdModel::Linear(0.0, 0.5),
+    );
+    assert!(matches!(bad_decay, Err(ProposalError::Decay(_))));
+}
+
+#[test]
+fn test_repeat_votes_collapse_to_latest() {
+    let mut proposal = Proposal::new(
+        "Dedup".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    );
+    let validator_id = Uuid::new_v4();
+
+    for (choice, offset) in [
+        (VoteChoice::Yes, 30),
+        (VoteChoice::No, 20),
+        (VoteChoice::Yes, 10),
+    ] {
+        let mut vote = dummy_vote(choice, offset, 0);
+        vote.validator_id = validator_id;
+        proposal.add_vote(vote);
+    }
+    proposal.add_vote(dummy_vote(VoteChoice::No, 0, 0));
+
+    assert_eq!(proposal.votes().len(), 2);
+    let latest = proposal
+        .votes()
+        .iter()
+        .find(|v| v.validator_id == validator_id)
+        .unwrap();
+    assert_eq!(latest.revision, 2);
+    assert_eq!(latest.choice, VoteChoice::Yes);
+}
+
+#[test]
+fn test_quorum_blocks_acceptance() {
+    let now = Utc::now();
+    let mut proposal = Proposal::new(
+        "Quorum".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    )
+    .with_quorum(5);
+
+    for _ in 0..3 {
+        proposal.add_vote(dummy_vote(VoteChoice::Yes, 0, 0));
+    }
+    proposal.evaluate(now + Duration::seconds(60));
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+
+    proposal.evaluate(now + Duration::seconds(610));
+    assert_eq!(proposal.status, ProposalStatus::NoQuorum);
+}
+
+#[test]
+fn test_abstain_counts_toward_quorum() {
+    let now = Utc::now();
+    let mut proposal = Proposal::new(
+        "Quorum".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    )
+    .with_quorum(5);
+
+    for _ in 0..3 {
+        proposal.add_vote(dummy_vote(VoteChoice::Yes, 0, 0));
+    }
+    proposal.add_vote(dummy_vote(VoteChoice::Abstain, 0, 0));
+    proposal.add_vote(dummy_vote(VoteChoice::Abstain, 0, 0));
+    assert!(proposal.quorum_met());
+
+    proposal.evaluate(now + Duration::seconds(60));
+    assert_eq!(proposal.status, ProposalStatus::Accepted);
+}
+
+#[test]
+fn test_overwhelming_no_rejects_early() {
+    let now = Utc::now();
+    let mut proposal = Proposal::new(
+        "Doomed".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.6),
+    );
+    proposal.max_validators = Some(10);
+
+    for _ in 0..8 {
+        proposal.add_vote(dummy_vote(VoteChoice::No, 0, 0));
+    }
+    proposal.evaluate(now + Duration::seconds(60));
+
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+}
+
+#[test]
+fn test_reachable_threshold_stays_pending() {
+    let now = Utc::now();
+    let mut proposal = Proposal::new(
+        "Open".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.6),
+    );
+    proposal.max_validators = Some(10);
+
+    for _ in 0..3 {
+        proposal.add_vote(dummy_vote(VoteChoice::No, 0, 0));
+    }
+    proposal.evaluate(now + Duration::seconds(60));
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+
+    let mut unbounded = proposal.clone();
+    unbounded.max_validators = None;
+    for _ in 0..8 {
+        unbounded.add_vote(dummy_vote(VoteChoice::No, 0, 0));
+    }
+    unbounded.evaluate(now + Duration::seconds(60));
+    assert_eq!(unbounded.status, ProposalStatus::Pending);
+}
+
+#[test]
+fn test_high_stake_voters_keep_threshold_reachable() {
+    let now = Utc::now();
+    let mut proposal = Proposal::new(
+        "Whale".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.6),
+    )
+    .with_max_stake(100);
+    proposal.max_validators = Some(10);
+
+    for _ in 0..8 {
+        proposal.add_vote(dummy_vote(VoteChoice::No, 0, 0));
+    }
+    let mut uncapped = proposal.clone();
+    proposal.evaluate(now + Duration::seconds(60));
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+
+    let whale = Vote {
+        stake: 100,
+        timestamp: now + Duration::seconds(60),
+        ..dummy_vote(VoteChoice::Yes, 0, 0)
+    };
+    assert!(!proposal.add_vote(Vote {
+        stake: 101,
+        ..whale.clone()
+    }));
+    assert!(proposal.add_vote(whale.clone()));
+    proposal.evaluate(now + Duration::seconds(61));
+    assert_eq!(proposal.status, ProposalStatus::Accepted);
+
+    uncapped.max_stake = None;
+    let mut mixed = uncapped.clone();
+    uncapped.evaluate(now + Duration::seconds(60));
+    assert_eq!(uncapped.status, ProposalStatus::Rejected);
+
+    mixed.add_vote(Vote {
+        stake: 5,
+        ..dummy_vote(VoteChoice::No, 0, 0)
+    });
+    mixed.evaluate(now + Duration::seconds(60));
+    assert_eq!(mixed.status, ProposalStatus::Pending);
+}
+
+fn vote_at(choice: VoteChoice, timestamp: chrono::DateTime<Utc>) -> Vote {
+    Vote {
+        validator_id: Uuid::new_v4(),
+        choice,
+        timestamp,
+        revision: 0,
+        reason: None,
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
+    }
+}
+
+#[test]
+fn test_evaluate_detailed_accepted() {
+    let mut proposal = Proposal::new(
+        "Detail".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    );
+    let start = proposal.voting_window.start_time;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    proposal.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(300)));
+
+    let outcome = proposal.evaluate_detailed(start + Duration::seconds(400));
+
+    assert_eq!(outcome.status, ProposalStatus::Accepted);
+    assert_eq!(outcome.elapsed, 400);
+    assert_eq!(outcome.participants, 2);
+    assert!((outcome.threshold - 0.5).abs() < 1e-9);
+    assert!((outcome.approval_ratio - 1.0 / 1.5).abs() < 1e-9);
+    assert_eq!(proposal.status, ProposalStatus::Accepted);
+}
+
+#[test]
+fn test_evaluate_detailed_rejected() {
+    let mut proposal = Proposal::new(
+        "Detail".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.7),
+    );
+    let start = proposal.voting_window.start_time;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    proposal.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(300)));
+    proposal.add_vote(vote_at(VoteChoice::Abstain, start));
+
+    let outcome = proposal.evaluate_detailed(start + Duration::seconds(610));
+
+    assert_eq!(outcome.status, ProposalStatus::Rejected);
+    assert_eq!(outcome.elapsed, 610);
+    assert_eq!(outcome.participants, 3);
+    assert!((outcome.threshold - 0.7).abs() < 1e-9);
+    assert!((outcome.approval_ratio - 1.0 / 1.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_high_stake_no_outweighs_small_yes_votes() {
+    let now = Utc::now();
+    let mut proposal = Proposal::new(
+        "Stake".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    );
+
+    for _ in 0..5 {
+        proposal.add_vote(dummy_vote(VoteChoice::Yes, 0, 0));
+    }
+    let mut whale = dummy_vote(VoteChoice::No, 0, 0);
+    whale.stake = 100;
+    proposal.add_vote(whale);
+
+    assert!((proposal.current_approval_ratio() - 5.0 / 105.0).abs() < 0.01);
+    proposal.evaluate(now + Duration::seconds(60));
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+}
+
+fn delegation_proposal() -> Proposal {
+    Proposal::new(
+        "Delegation".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    )
+}
+
+#[test]
+fn test_delegation_chain_follows_final_delegate() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let yes = vote_at(VoteChoice::Yes, start);
+    let c = yes.validator_id;
+    proposal.add_vote(yes);
+    proposal.add_vote(vote_at(VoteChoice::No, start));
+    proposal.add_vote(vote_at(VoteChoice::No, start));
+
+    // A -> B -> C, and C voted Yes
+    proposal.delegate(a, b).unwrap();
+    proposal.delegate(b, c).unwrap();
+
+    assert!((proposal.current_approval_ratio() - 3.0 / 5.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_delegation_cycle_is_skipped() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    proposal.add_vote(vote_at(VoteChoice::No, start));
+
+    // A -> B -> C -> A never reaches a direct voter
+    proposal.delegate(a, b).unwrap();
+    proposal.delegate(b, c).unwrap();
+    proposal.delegate(c, a).unwrap();
+
+    assert!((proposal.current_approval_ratio() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_direct_vote_overrides_delegation() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+
+    let yes = vote_at(VoteChoice::Yes, start);
+    let no = vote_at(VoteChoice::No, start);
+    proposal
+        .delegate(no.validator_id, yes.validator_id)
+        .unwrap();
+    proposal.add_vote(yes);
+    proposal.add_vote(no);
+
+    assert!((proposal.current_approval_ratio() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_revision_penalty_is_configurable_per_proposal() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    let validator_id = Uuid::new_v4();
+
+    for (revision, choice) in [VoteChoice::No, VoteChoice::No, VoteChoice::Yes]
+        .into_iter()
+        .enumerate()
+    {
+        let mut vote = vote_at(choice, start);
+        vote.validator_id = validator_id;
+        vote.revision = revision as u64;
+        proposal.add_vote(vote);
+    }
+    proposal.add_vote(vote_at(VoteChoice::No, start));
+
+    // Quadratic: revision 2 weighs 1/9 against a fresh No at 1.0
+    let revised = 1.0 / 9.0;
+    assert!((proposal.current_approval_ratio() - revised / (revised + 1.0)).abs() < 1e-9);
+
+    proposal.revision_penalty = RevisionPenalty::None;
+    assert!((proposal.current_approval_ratio() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_authorized_veto_rejects_proposal() {
+    let now = Utc::now();
+    let mut proposal = delegation_proposal();
+    for _ in 0..5 {
+        proposal.add_vote(dummy_vote(VoteChoice::Yes, 0, 0));
+    }
+    let veto = dummy_vote(VoteChoice::Veto, 0, 0);
+    proposal.grant_veto(veto.validator_id);
+    proposal.add_vote(veto);
+
+    assert!((proposal.current_approval_ratio() - 1.0).abs() < 1e-9);
+    proposal.evaluate(now + Duration::seconds(60));
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+}
+
+#[test]
+fn test_unauthorized_veto_is_ignored() {
+    let now = Utc::now();
+    let mut proposal = delegation_proposal();
+    for _ in 0..5 {
+        proposal.add_vote(dummy_vote(VoteChoice::Yes, 0, 0));
+    }
+    proposal.grant_veto(Uuid::new_v4());
+    proposal.add_vote(dummy_vote(VoteChoice::Veto, 0, 0));
+
+    assert!(!proposal.is_vetoed());
+    proposal.evaluate(now + Duration::seconds(60));
+    assert_eq!(proposal.status, ProposalStatus::Accepted);
+}
+
+#[test]
+fn test_min_threshold_enforces_supermajority() {
+    let now = Utc::now();
+    let mut proposal = delegation_proposal().with_min_threshold(0.667);
+    for choice in [
+        VoteChoice::Yes,
+        VoteChoice::Yes,
+        VoteChoice::Yes,
+        VoteChoice::No,
+        VoteChoice::No,
+    ] {
+        proposal.add_vote(dummy_vote(choice, 0, 0));
+    }
+
+    let outcome = proposal.evaluate_detailed(now + Duration::seconds(60));
+
+    assert!((outcome.approval_ratio - 0.6).abs() < 1e-9);
+    assert!((outcome.threshold - 0.667).abs() < 1e-9);
+    assert_eq!(outcome.status, ProposalStatus::Pending);
+}
+
+#[test]
+fn test_min_threshold_must_be_a_ratio() {
+    let proposal = delegation_proposal().with_min_threshold(1.5);
+    assert_eq!(
+        proposal.validate(),
+        Err(ProposalError::MinThresholdOutOfRange(1.5))
+    );
+    assert!(
+        delegation_proposal()
+            .with_min_threshold(0.667)
+            .validate()
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_quadratic_mode_takes_square_root_of_credits() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+
+    let mut big_spender = vote_at(VoteChoice::Yes, start);
+    big_spender.stake = 9;
+    proposal.add_vote(big_spender);
+    for _ in 0..3 {
+        proposal.add_vote(vote_at(VoteChoice::No, start));
+    }
+
+    assert!((proposal.current_approval_ratio() - 9.0 / 12.0).abs() < 1e-9);
+
+    proposal.weight_mode = VoteWeightMode::Quadratic;
+    assert!((proposal.current_approval_ratio() - 3.0 / 6.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_weighted_tally_matches_manual_calculation() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+
+    // Yes at the start (1.0), Yes halfway (0.5), No at 20% (0.8)
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(300)));
+    proposal.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(120)));
+
+    // Revised No: 0.9 / 4 = 0.225
+    let mut revised = vote_at(VoteChoice::Yes, start + Duration::seconds(60));
+    let revised_id = revised.validator_id;
+    proposal.add_vote(revised.clone());
+    revised.choice = VoteChoice::No;
+    revised.validator_id = revised_id;
+    revised.revision = 1;
+    proposal.add_vote(revised);
+
+    proposal.add_vote(vote_at(VoteChoice::Abstain, start));
+    proposal.add_vote(vote_at(VoteChoice::Abstain, start));
+
+    let tally = proposal.weighted_tally();
+    assert!((tally.yes_weight - 1.5).abs() < 1e-9);
+    assert!((tally.no_weight - 1.025).abs() < 1e-9);
+    assert_eq!(tally.abstain_count, 2);
+    assert_eq!(tally.total_validators, 6);
+    assert!((tally.approval_ratio - 1.5 / 2.525).abs() < 1e-9);
+    assert_eq!(tally.approval_ratio, proposal.current_approval_ratio());
+}
+
+#[test]
+fn test_capped_extensions_let_proposal_resolve() {
+    let now = Utc::now();
+    let mut proposal = Proposal::new(
+        "Close call".into(),
+        "Desc".into(),
+        100,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.9),
+    );
+    proposal.voting_window = proposal.voting_window.clone().with_max_extensions(1);
+    proposal.voting_window.start_time = now - Duration::seconds(95);
+    proposal.add_vote(dummy_vote(VoteChoice::Yes, 0, 0));
+    proposal.add_vote(dummy_vote(VoteChoice::Yes, 0, 0));
+    proposal.add_vote(dummy_vote(VoteChoice::No, 0, 0));
+
+    assert!(proposal.extend_window(now, 10, 0.7, 0.9));
+    assert!(!proposal.extend_window(now + Duration::seconds(10), 10, 0.7, 0.9));
+    assert_eq!(proposal.voting_window.extended_by, 10);
+
+    proposal.evaluate(now + Duration::seconds(16));
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+}
+
+#[test]
+fn test_add_vote_at_flags_grace_period_votes() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+
+    let open = vote_at(VoteChoice::Yes, start + Duration::seconds(100));
+    let open_id = open.validator_id;
+    proposal.add_vote_at(open, start + Duration::seconds(100));
+
+    let late = vote_at(VoteChoice::No, start + Duration::seconds(610));
+    let late_id = late.validator_id;
+    proposal.add_vote_at(late, start + Duration::seconds(610));
+
+    let flag = |id| {
+        proposal
+            .votes()
+            .iter()
+            .find(|v| v.validator_id == id)
+            .unwrap()
+            .cast_during_grace
+    };
+    assert!(!flag(open_id));
+    assert!(flag(late_id));
+}
+
+#[test]
+fn test_builder_minimal_proposal() {
+    let proposal = Proposal::builder()
+        .title("Minimal")
+        .duration(600)
+        .decay_model(DecayModel::Linear)
+        .threshold_model(ThresholdModel::Linear(0.0, 0.5))
+        .build()
+        .unwrap();
+
+    assert_eq!(proposal.title, "Minimal");
+    assert_eq!(proposal.description, "");
+    assert_eq!(proposal.voting_window.duration, 600);
+    assert_eq!(proposal.voting_window.grace_period, 30);
+    assert_eq!(proposal.quorum, None);
+    assert!(proposal.tags.is_empty());
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+}
+
+#[test]
+fn test_builder_with_all_options() {
+    let proposal = Proposal::builder()
+        .title("Full")
+        .description("Every option set")
+        .duration(3600)
+        .grace_period(600)
+        .decay_model(DecayModel::Exponential(0.001))
+        .threshold_model(ThresholdModel::Sigmoid(4.0, 0.3))
+        .quorum(7)
+        .tags(vec!["treasury".into()])
+        .build()
+        .unwrap();
+
+    assert_eq!(proposal.description, "Every option set");
+    assert_eq!(proposal.voting_window.grace_period, 600);
+    assert_eq!(proposal.threshold_model, ThresholdModel::Sigmoid(4.0, 0.3));
+    assert_eq!(proposal.quorum, Some(7));
+    assert!(proposal.has_tag("Treasury"));
+}
+
+#[test]
+fn test_builder_reports_missing_and_invalid_fields() {
+    let missing = Proposal::builder().title("No models").duration(600).build();
+    assert_eq!(
+        missing.unwrap_err(),
+        BuildError::MissingField("decay_model")
+    );
+
+    let invalid = Proposal::builder()
+        .title("Flat sigmoid")
+        .duration(600)
+        .decay_model(DecayModel::Linear)
+        .threshold_model(ThresholdModel::Sigmoid(0.0, 0.5))
+        .build();
+    assert!(matches!(
+        invalid,
+        Err(BuildError::Invalid(ProposalError::Threshold(_)))
+    ));
+}
+
+#[test]
+fn test_new_uses_default_grace_period() {
+    let proposal = Proposal::new(
+        "Default grace".into(),
+        "".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    );
+    assert_eq!(proposal.voting_window.grace_period, DEFAULT_GRACE_PERIOD);
+}
+
+#[test]
+fn test_long_grace_period_delays_expiry() {
+    let proposal = Proposal::builder()
+        .title("Day-long vote")
+        .duration(600)
+        .grace_period(3600)
+        .decay_model(DecayModel::Linear)
+        .threshold_model(ThresholdModel::Linear(0.0, 0.5))
+        .build()
+        .unwrap();
+    let start = proposal.voting_window.start_time;
+
+    let mut within_grace = proposal.clone();
+    within_grace.evaluate(start + Duration::seconds(600 + 1800));
+    assert_ne!(within_grace.status, ProposalStatus::Expired);
+
+    let mut past_grace = proposal.clone();
+    past_grace.evaluate(start + Duration::seconds(600 + 3601));
+    assert_eq!(past_grace.status, ProposalStatus::Expired);
+}
+
+#[test]
+fn test_history_records_acceptance() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+
+    let now = start + Duration::seconds(10);
+    proposal.evaluate(now);
+    proposal.evaluate(now + Duration::seconds(10));
+
+    assert_eq!(proposal.history.len(), 1);
+    let transition = &proposal.history[0];
+    assert_eq!(transition.from, ProposalStatus::Pending);
+    assert_eq!(transition.to, ProposalStatus::Accepted);
+    assert_eq!(transition.at, now);
+    assert!((transition.approval_ratio - 1.0).abs() < 1e-9);
+    assert!((transition.threshold - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_history_survives_serde_round_trip() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    proposal.add_vote(vote_at(VoteChoice::No, start));
+    proposal.evaluate(start + Duration::seconds(610));
+
+    let json = serde_json::to_string(&proposal).unwrap();
+    let restored: Proposal = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.history, proposal.history);
+    assert_eq!(restored.history[0].to, ProposalStatus::Rejected);
+}
+
+#[test]
+fn test_weight_quorum_blocks_late_low_weight_votes() {
+    let mut proposal = delegation_proposal().with_quorum(3).with_weight_quorum(1.0);
+    let start = proposal.voting_window.start_time;
+    for _ in 0..3 {
+        proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(540)));
+    }
+
+    assert!(proposal.quorum_met());
+    assert!(!proposal.weight_quorum_met());
+    proposal.evaluate(start + Duration::seconds(550));
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+}
+
+#[test]
+fn test_weight_quorum_met_by_early_votes() {
+    let mut proposal = delegation_proposal().with_weight_quorum(1.5);
+    let start = proposal.voting_window.start_time;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    proposal.add_vote(vote_at(VoteChoice::Abstain, start));
+    assert!(!proposal.weight_quorum_met());
+
+    proposal.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(60)));
+    assert!(proposal.weight_quorum_met());
+    proposal.evaluate(start + Duration::seconds(60));
+    assert_eq!(proposal.status, ProposalStatus::Accepted);
+}
+
+#[test]
+fn test_abstain_mode_changes_approval_ratio() {
+    let mut ignoring = delegation_proposal();
+    let mut counting = delegation_proposal().with_abstain_mode(AbstainMode::CountInDenominator);
+    let start = ignoring.voting_window.start_time;
+    counting.voting_window.start_time = start;
+    let votes = [
+        vote_at(VoteChoice::Yes, start),
+        vote_at(VoteChoice::Yes, start),
+        vote_at(VoteChoice::No, start),
+        vote_at(VoteChoice::Abstain, start),
+    ];
+
+    for vote in &votes {
+        ignoring.add_vote(vote.clone());
+        counting.add_vote(vote.clone());
+    }
+
+    assert_eq!(ignoring.abstain_mode, AbstainMode::Ignore);
+    assert!((ignoring.current_approval_ratio() - 2.0 / 3.0).abs() < 1e-9);
+    assert!((counting.current_approval_ratio() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_abstain_mode_can_block_acceptance() {
+    let mut proposal = delegation_proposal().with_abstain_mode(AbstainMode::CountInDenominator);
+    let start = proposal.voting_window.start_time;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    proposal.add_vote(vote_at(VoteChoice::Abstain, start));
+    proposal.add_vote(vote_at(VoteChoice::Abstain, start));
+
+    proposal.evaluate(start + Duration::seconds(1));
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+}
+
+#[test]
+fn test_cancel_pending_proposal() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+
+    assert!(proposal.cancel());
+    assert_eq!(proposal.status, ProposalStatus::Cancelled);
+    assert!(proposal.status.is_final());
+    assert_eq!(
+        proposal.history.last().unwrap().to,
+        ProposalStatus::Cancelled
+    );
+
+    proposal.evaluate(start + Duration::seconds(10));
+    assert_eq!(proposal.status, ProposalStatus::Cancelled);
+}
+
+#[test]
+fn test_cancel_refused_once_accepted() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    proposal.evaluate(start + Duration::seconds(10));
+
+    assert!(!proposal.cancel());
+    assert_eq!(proposal.status, ProposalStatus::Accepted);
+    assert_eq!(proposal.history.len(), 1);
+}
+
+#[test]
+fn test_try_add_vote_rejects_pre_start_vote() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+
+    let result = proposal.try_add_vote(vote_at(VoteChoice::Yes, start - Duration::seconds(1)));
+    assert_eq!(result, Err(VoteError::BeforeWindowStart));
+    assert!(proposal.votes().is_empty());
+}
+
+#[test]
+fn test_try_add_vote_rejects_post_grace_vote() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    let past_grace = start + Duration::seconds(600 + DEFAULT_GRACE_PERIOD as i64 + 1);
+
+    let result = proposal.try_add_vote(vote_at(VoteChoice::Yes, past_grace));
+    assert_eq!(result, Err(VoteError::AfterGracePeriod));
+    assert!(proposal.votes().is_empty());
+}
+
+#[test]
+fn test_try_add_vote_accepts_in_window_vote() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+
+    assert!(
+        proposal
+            .try_add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(60)))
+            .is_ok()
+    );
+    assert_eq!(proposal.votes().len(), 1);
+}
+
+#[test]
+fn test_snapshots_at_same_instant_are_identical() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(30)));
+    proposal.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(300)));
+
+    let now = start + Duration::seconds(400);
+    let first = proposal.snapshot(now);
+    let second = proposal.snapshot(now);
+
+    assert_eq!(first, second);
+    assert_eq!(first.vote_weights.len(), 2);
+    assert_eq!(first.elapsed, 400);
+    assert!((first.vote_weights[0].1 - 0.95).abs() < 1e-9);
+}
+
+#[test]
+fn test_evaluate_stores_snapshot_when_enabled() {
+    let mut proposal = delegation_proposal().with_snapshots(true);
+    let start = proposal.voting_window.start_time;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+
+    let now = start + Duration::seconds(10);
+    proposal.evaluate(now);
+    let snapshot = proposal.last_snapshot.clone().unwrap();
+    assert_eq!(snapshot.status, ProposalStatus::Accepted);
+    assert_eq!(snapshot, proposal.snapshot(now));
+
+    let mut plain = delegation_proposal();
+    plain.evaluate(now);
+    assert!(plain.last_snapshot.is_none());
+}
+
+#[test]
+fn test_abstain_weight_decays_over_time() {
+    let mut early = delegation_proposal();
+    let start = early.voting_window.start_time;
+    early.add_vote(vote_at(VoteChoice::Abstain, start + Duration::seconds(1)));
+
+    let mut late = delegation_proposal();
+    late.voting_window.start_time = start;
+    late.add_vote(vote_at(VoteChoice::Abstain, start + Duration::seconds(599)));
+
+    let early = early.weighted_tally();
+    let late = late.weighted_tally();
+    assert!((early.abstain_weight - 1.0).abs() < 0.01);
+    assert!((late.abstain_weight - 0.1).abs() < 0.01);
+    assert_eq!(early.approval_ratio, 0.0);
+    assert_eq!(late.abstain_count, 1);
+}
+
+#[test]
+fn test_abstain_weight_stays_out_of_ratio_by_default() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    proposal.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(300)));
+    proposal.add_vote(vote_at(VoteChoice::Abstain, start + Duration::seconds(300)));
+
+    let tally = proposal.weighted_tally();
+    assert!((tally.abstain_weight - 0.5).abs() < 1e-9);
+    assert!((tally.approval_ratio - 1.0 / 1.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_zero_duration_is_rejected() {
+    let result = Proposal::try_new(
+        "Instant".into(),
+        "".into(),
+        0,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    );
+    assert_eq!(result.unwrap_err(), ProposalError::ZeroDuration);
+
+    let built = Proposal::builder()
+        .title("Instant")
+        .duration(0)
+        .decay_model(DecayModel::Linear)
+        .threshold_model(ThresholdModel::Linear(0.0, 0.5))
+        .build();
+    assert_eq!(
+        built.unwrap_err(),
+        BuildError::Invalid(ProposalError::ZeroDuration)
+    );
+}
+
+#[test]
+fn test_previous_choices_track_changed_mind() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    let validator = Uuid::new_v4();
+    let cast = |choice, revision, secs, reason: &str| Vote {
+        validator_id: validator,
+        revision,
+        reason: Some(reason.into()),
+        ..vote_at(choice, start + Duration::seconds(secs))
+    };
+
+    proposal.add_vote(cast(VoteChoice::Yes, 0, 10, "initial support"));
+    proposal.add_vote(cast(VoteChoice::No, 1, 20, "found a flaw"));
+    proposal.add_vote(cast(VoteChoice::Yes, 2, 30, "flaw fixed"));
+
+    assert_eq!(
+        proposal.previous_choices(validator),
+        vec![
+            (VoteChoice::Yes, start + Duration::seconds(10)),
+            (VoteChoice::No, start + Duration::seconds(20)),
+        ]
+    );
+    let reasons: Vec<_> = proposal.replaced_votes[&validator]
+        .iter()
+        .map(|v| v.reason.as_deref().unwrap())
+        .collect();
+    assert_eq!(reasons, vec!["initial support", "found a flaw"]);
+    assert_eq!(proposal.votes().len(), 1);
+    assert_eq!(proposal.votes()[0].choice, VoteChoice::Yes);
+}
+
+#[test]
+fn test_previous_choices_empty_for_single_vote() {
+    let mut proposal = delegation_proposal();
+    let vote = vote_at(VoteChoice::No, proposal.voting_window.start_time);
+    let validator = vote.validator_id;
+    proposal.add_vote(vote);
+
+    assert!(proposal.previous_choices(validator).is_empty());
+    assert!(proposal.previous_choices(Uuid::new_v4()).is_empty());
+}
+
+#[test]
+fn test_weight_concentration_equal_weights() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    for _ in 0..4 {
+        proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    }
+    assert!(proposal.weight_concentration().abs() < 1e-9);
+    assert_eq!(delegation_proposal().weight_concentration(), 0.0);
+}
+
+#[test]
+fn test_weight_concentration_skewed_weights() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    for _ in 0..9 {
+        proposal.add_vote(vote_at(VoteChoice::No, start));
+    }
+    proposal.add_vote(Vote {
+        stake: 1000,
+        ..vote_at(VoteChoice::Yes, start)
+    });
+
+    let gini = proposal.weight_concentration();
+    assert!((gini - 0.891).abs() < 0.001, "gini was {gini}");
+}
+
+#[test]
+fn test_revealed_commitment_counts() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    let validator = Uuid::new_v4();
+    let committed_at = start + Duration::seconds(60);
+    proposal
+        .commit(
+            VoteCommitment {
+                validator_id: validator,
+                commitment: commitment_hash(&VoteChoice::Yes, "s3cret"),
+                timestamp: committed_at,
+                stake: 1,
+            },
+            committed_at,
+        )
+        .unwrap();
+    assert_eq!(proposal.current_approval_ratio(), 0.0);
+    assert!(proposal.votes().is_empty());
+
+    assert_eq!(
+        proposal.reveal(
+            validator,
+            VoteChoice::Yes,
+            "s3cret",
+            start + Duration::seconds(610)
+        ),
+        Ok(())
+    );
+    assert!(proposal.commitments.is_empty());
+    assert_eq!(proposal.votes()[0].timestamp, start + Duration::seconds(60));
+    assert_eq!(proposal.current_approval_ratio(), 1.0);
+}
+
+#[test]
+fn test_revealed_commitment_keeps_its_stake() {
+    let mut proposal = delegation_proposal().with_max_stake(100);
+    proposal.weight_mode = VoteWeightMode::Quadratic;
+    let start = proposal.voting_window.start_time;
+    let validator = Uuid::new_v4();
+    let sealed = VoteCommitment {
+        validator_id: validator,
+        commitment: commitment_hash(&VoteChoice::Yes, "s3cret"),
+        timestamp: start,
+        stake: 101,
+    };
+    assert_eq!(
+        proposal.commit(sealed.clone(), start),
+        Err(VoteError::StakeTooHigh)
+    );
+    proposal
+        .commit(
+            VoteCommitment {
+                stake: 100,
+                ..sealed
+            },
+            start,
+        )
+        .unwrap();
+    proposal.add_vote(Vote {
+        stake: 25,
+        ..vote_at(VoteChoice::No, start)
+    });
+
+    proposal
+        .reveal(
+            validator,
+            VoteChoice::Yes,
+            "s3cret",
+            start + Duration::seconds(610),
+        )
+        .unwrap();
+    let revealed = proposal
+        .votes()
+        .iter()
+        .find(|v| v.validator_id == validator)
+        .unwrap();
+    assert_eq!(revealed.stake, 100);
+    // sqrt(100) = 10 against sqrt(25) = 5
+    assert!((proposal.current_approval_ratio() - 2.0 / 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_mismatched_reveal_is_rejected() {
+    let mut proposal = delegation_proposal();
+    let validator = Uuid::new_v4();
+    let start = proposal.voting_window.start_time;
+    proposal
+        .commit(
+            VoteCommitment {
+                validator_id: validator,
+                commitment: commitment_hash(&VoteChoice::No, "s3cret"),
+                timestamp: start,
+                stake: 1,
+            },
+            start,
+        )
+        .unwrap();
+
+    let grace = start + Duration::seconds(610);
+    assert_eq!(
+        proposal.reveal(validator, VoteChoice::Yes, "s3cret", grace),
+        Err(VoteError::CommitmentMismatch)
+    );
+    assert_eq!(
+        proposal.reveal(validator, VoteChoice::No, "guess", grace),
+        Err(VoteError::CommitmentMismatch)
+    );
+    assert_eq!(
+        proposal.reveal(Uuid::new_v4(), VoteChoice::No, "s3cret", grace),
+        Err(VoteError::NoCommitment)
+    );
+    assert!(proposal.votes().is_empty());
+    assert!(proposal.commitments.contains_key(&validator));
+}
+
+#[test]
+fn test_resubmitted_vote_is_ignored() {
+    let mut proposal = delegation_proposal();
+    let vote = vote_at(VoteChoice::Yes, proposal.voting_window.start_time);
+
+    assert!(proposal.add_vote(vote.clone()));
+    assert!(!proposal.add_vote(vote.clone()));
+    assert_eq!(proposal.votes().len(), 1);
+    assert!(proposal.replaced_votes.is_empty());
+    assert_eq!(
+        proposal.try_add_vote(vote.clone()),
+        Err(VoteError::Duplicate)
+    );
+
+    // A different vote at the same revision is still an upsert
+    let changed = Vote {
+        choice: VoteChoice::No,
+        ..vote.clone()
+    };
+    assert!(proposal.add_vote(changed.clone()));
+    assert!(!proposal.add_vote(changed));
+    assert!(!proposal.add_vote(vote));
+
+    assert_eq!(proposal.votes().len(), 1);
+    assert_eq!(proposal.votes()[0].choice, VoteChoice::No);
+    assert_eq!(proposal.votes()[0].revision, 1);
+}
+
+#[test]
+fn test_bumped_revision_replaces_vote() {
+    let mut proposal = delegation_proposal();
+    let vote = vote_at(VoteChoice::Yes, proposal.voting_window.start_time);
+    proposal.add_vote(vote.clone());
+
+    let bumped = Vote {
+        choice: VoteChoice::No,
+        revision: 1,
+        ..vote.clone()
+    };
+    assert_eq!(bumped.dedup_key(), (vote.validator_id, 1));
+    assert!(proposal.add_vote(bumped));
+
+    assert_eq!(proposal.votes().len(), 1);
+    assert_eq!(proposal.votes()[0].choice, VoteChoice::No);
+    assert_eq!(proposal.votes()[0].revision, 1);
+}
+
+#[test]
+fn test_evaluate_pure_does_not_mutate() {
+    let start = "2026-01-01T00:00:00Z"
+        .parse::<chrono::DateTime<Utc>>()
+        .unwrap();
+    let mut proposal = Proposal::new_at(
+        "Pure".into(),
+        "".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+        start,
+    );
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    let before = serde_json::to_string(&proposal).unwrap();
+
+    let outcome = evaluate_pure(&proposal, start + Duration::seconds(60));
+    assert_eq!(outcome.status, ProposalStatus::Accepted);
+    assert_eq!(outcome.elapsed, 60);
+    assert_eq!(serde_json::to_string(&proposal).unwrap(), before);
+    assert_eq!(
+        outcome,
+        proposal.evaluate_detailed(start + Duration::seconds(60))
+    );
+}
+
+#[test]
+fn test_evaluate_pure_handles_degenerate_proposals() {
+    let start = "2026-01-01T00:00:00Z"
+        .parse::<chrono::DateTime<Utc>>()
+        .unwrap();
+    let empty = Proposal::new_at(
+        "Empty".into(),
+        "".into(),
+        600,
+        DecayModel::Polynomial(2.0),
+        ThresholdModel::Sigmoid(4.0, 0.3),
+        start,
+    );
+    assert_eq!(evaluate_pure(&empty, start).status, ProposalStatus::Pending);
+    assert_eq!(evaluate_pure(&empty, start - Duration::days(1)).elapsed, 0);
+
+    let instant = Proposal::new_at(
+        "Instant".into(),
+        "".into(),
+        0,
+        DecayModel::Linear,
+        ThresholdModel::Sigmoid(4.0, 0.3),
+        start,
+    );
+    let outcome = evaluate_pure(&instant, start);
+    assert_eq!(outcome.status, ProposalStatus::Rejected);
+    assert!(outcome.threshold.is_finite());
+    assert_eq!(outcome.approval_ratio, 0.0);
+}
+
+#[test]
+fn test_effective_participation_counts_delegators() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+    let (c, d, e) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    for voter in [a, b] {
+        proposal.add_vote(Vote {
+            validator_id: voter,
+            ..vote_at(VoteChoice::Yes, start)
+        });
+    }
+    proposal.delegate(c, a).unwrap();
+    proposal.delegate(d, b).unwrap();
+    proposal.delegate(e, d).unwrap();
+    proposal.delegate(Uuid::new_v4(), Uuid::new_v4()).unwrap();
+
+    assert_eq!(proposal.voter_count(), 2);
+    assert_eq!(proposal.effective_participation(), 5);
+}
+
+#[test]
+fn test_delegators_count_toward_quorum_and_participation() {
+    let mut proposal = delegation_proposal().with_quorum(3);
+    proposal.max_validators = Some(4);
+    let start = proposal.voting_window.start_time;
+    let voter = Uuid::new_v4();
+    proposal.add_vote(Vote {
+        validator_id: voter,
+        ..vote_at(VoteChoice::Yes, start)
+    });
+    assert!(!proposal.quorum_met());
+
+    proposal.delegate(Uuid::new_v4(), voter).unwrap();
+    proposal.delegate(Uuid::new_v4(), voter).unwrap();
+    assert!(proposal.quorum_met());
+    assert!((proposal.participation() - 0.75).abs() < 1e-9);
+}
+
+#[test]
+fn test_rejection_reason_records_each_path() {
+    let start = Utc::now();
+
+    let mut threshold = delegation_proposal();
+    threshold.voting_window.start_time = start;
+    threshold.add_vote(vote_at(VoteChoice::No, start));
+    threshold.evaluate(start + Duration::seconds(610));
+    assert_eq!(threshold.status, ProposalStatus::Rejected);
+    assert_eq!(
+        threshold.rejection_reason,
+        Some(RejectionReason::ThresholdNotMet)
+    );
+
+    let mut quorum = delegation_proposal().with_quorum(3);
+    quorum.voting_window.start_time = start;
+    quorum.add_vote(vote_at(VoteChoice::Yes, start));
+    quorum.evaluate(start + Duration::seconds(610));
+    assert_eq!(quorum.status, ProposalStatus::NoQuorum);
+    assert_eq!(quorum.rejection_reason, Some(RejectionReason::QuorumNotMet));
+
+    let mut vetoed = delegation_proposal();
+    vetoed.voting_window.start_time = start;
+    let veto = vote_at(VoteChoice::Veto, start);
+    vetoed.grant_veto(veto.validator_id);
+    vetoed.add_vote(veto);
+    vetoed.evaluate(start + Duration::seconds(60));
+    assert_eq!(vetoed.status, ProposalStatus::Rejected);
+    assert_eq!(vetoed.rejection_reason, Some(RejectionReason::Vetoed));
+
+    let mut expired = delegation_proposal();
+    expired.voting_window.start_time = start;
+    expired.evaluate(start + Duration::seconds(700));
+    assert_eq!(expired.status, ProposalStatus::Expired);
+    assert_eq!(expired.rejection_reason, Some(RejectionReason::Expired));
+}
+
+#[test]
+fn test_accepted_proposal_has_no_rejection_reason() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+
+    let outcome = evaluate_pure(&proposal, start + Duration::seconds(60));
+
+    assert_eq!(outcome.status, ProposalStatus::Accepted);
+    assert_eq!(outcome.rejection_reason, None);
+}
+
+#[test]
+fn test_expiry_time_and_deadline_follow_extensions() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(590)));
+
+    assert_eq!(proposal.voting_deadline(), start + Duration::seconds(600));
+    assert_eq!(
+        proposal.expiry_time(),
+        start + Duration::seconds(600 + DEFAULT_GRACE_PERIOD as i64)
+    );
+
+    assert!(proposal.extend_window(start + Duration::seconds(590), 60, 0.9, 0.9));
+    assert_eq!(proposal.voting_deadline(), start + Duration::seconds(660));
+    assert_eq!(
+        proposal.expiry_time(),
+        start + Duration::seconds(660 + DEFAULT_GRACE_PERIOD as i64)
+    );
+}
+
+#[test]
+fn test_weight_cache_matches_uncached_and_busts_on_extension() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(550)));
+    proposal.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(100)));
+    proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(580)));
+
+    let uncached = |proposal: &Proposal| {
+        let mut fresh = proposal.clone();
+        fresh.invalidate_weight_cache();
+        fresh.current_approval_ratio()
+    };
+
+    let expected = uncached(&proposal);
+    for _ in 0..1_000 {
+        assert_eq!(proposal.current_approval_ratio(), expected);
+    }
+
+    assert!(proposal.extend_window(start + Duration::seconds(590), 600, 0.0, 0.9));
+    let extended = proposal.current_approval_ratio();
+    assert_eq!(extended, uncached(&proposal));
+    assert_ne!(extended, expected);
+}
+
+#[test]
+fn test_vote_changed_under_another_model_refreshes_cache() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    let changer = vote_at(VoteChoice::No, start + Duration::seconds(100));
+    proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(50)));
+    proposal.add_vote(changer.clone());
+    let before = proposal.current_approval_ratio();
+
+    proposal.decay_model = DecayModel::Stepped;
+    proposal.add_vote(Vote {
+        choice: VoteChoice::Yes,
+        ..changer
+    });
+    proposal.decay_model = DecayModel::Linear;
+
+    let mut fresh = proposal.clone();
+    fresh.invalidate_weight_cache();
+    assert_eq!(proposal.current_approval_ratio(), 1.0);
+    assert_eq!(
+        proposal.current_approval_ratio(),
+        fresh.current_approval_ratio()
+    );
+    assert_ne!(proposal.current_approval_ratio(), before);
+}
+
+#[test]
+fn test_incremental_ratio_matches_full_recomputation() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal().with_abstain_mode(AbstainMode::CountInDenominator);
+    proposal.voting_window.start_time = start;
+    let full = |proposal: &Proposal| proposal.weighted_tally().approval_ratio;
+    let assert_matches = |proposal: &Proposal| {
+        assert!((proposal.current_approval_ratio() - full(proposal)).abs() < 1e-12);
+    };
+
+    assert_eq!(proposal.current_approval_ratio(), 0.0);
+    let changer = vote_at(VoteChoice::No, start + Duration::seconds(30));
+    let votes = [
+        changer.clone(),
+        vote_at(VoteChoice::Yes, start + Duration::seconds(120)),
+        vote_at(VoteChoice::Abstain, start + Duration::seconds(240)),
+        Vote {
+            stake: 4,
+            ..vote_at(VoteChoice::Yes, start + Duration::seconds(500))
+        },
+    ];
+    for vote in votes {
+        proposal.add_vote(vote);
+        assert_matches(&proposal);
+    }
+
+    proposal.add_vote(Vote {
+        choice: VoteChoice::Yes,
+        timestamp: start + Duration::seconds(300),
+        revision: 1,
+        ..changer
+    });
+    assert_matches(&proposal);
+
+    let before = proposal.current_approval_ratio();
+    assert!(proposal.extend_window(start + Duration::seconds(590), 600, 0.0, 0.9));
+    assert_matches(&proposal);
+    assert_ne!(proposal.current_approval_ratio(), before);
+
+    proposal.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(700)));
+    assert_matches(&proposal);
+}
+
+#[test]
+fn test_reopen_expired_proposal_accepts_new_votes() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    let late = start + Duration::seconds(900);
+    proposal.evaluate(late);
+    assert_eq!(proposal.status, ProposalStatus::Expired);
+
+    assert!(proposal.reopen(120, late));
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+    assert_eq!(proposal.rejection_reason, None);
+    assert_eq!(proposal.voting_deadline(), late + Duration::seconds(120));
+    let reopened = proposal.history.last().unwrap();
+    assert_eq!(reopened.from, ProposalStatus::Expired);
+    assert_eq!(reopened.to, ProposalStatus::Pending);
+
+    let vote = vote_at(VoteChoice::Yes, late + Duration::seconds(10));
+    assert_eq!(proposal.try_add_vote(vote), Ok(()));
+    proposal.evaluate(late + Duration::seconds(20));
+    assert_eq!(proposal.status, ProposalStatus::Accepted);
+}
+
+#[test]
+fn test_reopen_refuses_pending_and_accepted() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    assert!(!proposal.reopen(60, start));
+
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    proposal.evaluate(start + Duration::seconds(10));
+    assert_eq!(proposal.status, ProposalStatus::Accepted);
+    assert!(!proposal.reopen(60, start + Duration::seconds(10)));
+}
+
+#[test]
+fn test_reopen_counts_as_an_extension() {
+    let start = Utc::now();
+    let late = start + Duration::seconds(900);
+    let mut proposal = delegation_proposal();
+    proposal.voting_window = VotingWindow::new(start, 600, 30).with_max_extensions(1);
+    proposal.evaluate(late);
+
+    let mut capped = proposal.clone();
+    assert!(capped.voting_window.extend(60));
+    assert!(!capped.reopen(120, late));
+    assert_eq!(capped.status, ProposalStatus::Expired);
+
+    let mut by_policy = proposal.clone().with_extension_policy(ExtensionPolicy {
+        extension_seconds: 60,
+        threshold_proximity: 0.9,
+        time_proximity: 0.9,
+        max_extensions: Some(0),
+    });
+    assert!(!by_policy.reopen(120, late));
+
+    assert!(proposal.reopen(120, late));
+    assert_eq!(proposal.voting_window.extensions, 1);
+    assert!(!proposal.voting_window.can_extend());
+}
+
+#[test]
+fn test_reopen_releases_dependency_hold() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal().with_dependency(Uuid::new_v4());
+    proposal.voting_window.start_time = start;
+    proposal.voting_window.pause(start + Duration::seconds(100));
+    proposal.dependency_hold = true;
+    proposal.evaluate(start + Duration::seconds(100));
+    proposal.status = ProposalStatus::Rejected;
+
+    let now = start + Duration::seconds(1000);
+    assert!(proposal.reopen(120, now));
+    assert!(!proposal.dependency_hold);
+    assert!(!proposal.voting_window.is_paused());
+    assert_eq!(proposal.voting_window.state(now), WindowState::Extended);
+}
+
+#[test]
+fn test_vote_burst_near_expiry_extends_window() {
+    let start = Utc::now();
+    let policy = VelocityPolicy {
+        extension_seconds: 60,
+        window_seconds: 30,
+        min_velocity: 0.1,
+        time_proximity: 0.9,
+    };
+    let now = start + Duration::seconds(580);
+
+    let mut busy = delegation_proposal();
+    busy.voting_window.start_time = start;
+    for offset in [555, 560, 570, 575] {
+        busy.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(offset)));
+    }
+    assert!((busy.vote_velocity(30, now) - 4.0 / 30.0).abs() < 1e-9);
+    assert!(busy.extend_on_velocity(now, policy));
+    assert_eq!(busy.voting_window.extended_by, 60);
+
+    let mut quiet = delegation_proposal();
+    quiet.voting_window.start_time = start;
+    for offset in [10, 100, 200, 560] {
+        quiet.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(offset)));
+    }
+    assert!(quiet.vote_velocity(30, now) < policy.min_velocity);
+    assert!(!quiet.extend_on_velocity(now, policy));
+    assert_eq!(quiet.voting_window.extended_by, 0);
+}
+
+#[test]
+fn test_status_display_and_from_str() {
+    let names = [
+        "pending",
+        "accepted",
+        "rejected",
+        "expired",
+        "cancelled",
+        "no_quorum",
+    ];
+    for (status, name) in ProposalStatus::ALL.into_iter().zip(names) {
+        assert_eq!(status.to_string(), name);
+        assert_eq!(name.parse::<ProposalStatus>(), Ok(status.clone()));
+        assert_eq!(
+            name.to_uppercase().parse::<ProposalStatus>(),
+            Ok(status.clone())
+        );
+    }
+
+    assert_eq!(
+        "finalized".parse::<ProposalStatus>(),
+        Err(ParseStatusError("finalized".into()))
+    );
+    assert!("".parse::<ProposalStatus>().is_err());
+}
+
+#[test]
+fn test_status_serializes_as_stable_lowercase_string() {
+    for status in ProposalStatus::ALL {
+        let json = serde_json::to_string(&status).unwrap();
+        assert_eq!(json, format!("\"{status}\""));
+        let restored: ProposalStatus = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, status);
+    }
+
+    let legacy: ProposalStatus = serde_json::from_str("\"Accepted\"").unwrap();
+    assert_eq!(legacy, ProposalStatus::Accepted);
+}
+
+#[test]
+fn test_influence_ranking_orders_by_effective_weight() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+
+    let early = vote_at(VoteChoice::Yes, start);
+    let mid = vote_at(VoteChoice::No, start + Duration::seconds(300));
+    let late = vote_at(VoteChoice::Yes, start + Duration::seconds(590));
+    let abstain = vote_at(VoteChoice::Abstain, start);
+    let ids = [
+        early.validator_id,
+        mid.validator_id,
+        late.validator_id,
+        abstain.validator_id,
+    ];
+    for vote in [late, abstain, mid, early] {
+        proposal.add_vote(vote);
+    }
+
+    let ranking = proposal.influence_ranking();
+    let order: Vec<Uuid> = ranking.iter().map(|(id, _)| *id).collect();
+    assert_eq!(order, ids);
+    assert!((ranking[0].1 - 1.0).abs() < 1e-9);
+    assert!((ranking[1].1 - 0.5).abs() < 1e-9);
+    assert!((ranking[2].1 - 0.1).abs() < 1e-9);
+    assert_eq!(ranking[3].1, 0.0);
+}
+
+#[test]
+fn test_influence_ranking_breaks_ties_by_validator_id() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    let mut ids = Vec::new();
+    for _ in 0..4 {
+        let vote = vote_at(VoteChoice::Yes, start);
+        ids.push(vote.validator_id);
+        proposal.add_vote(vote);
+    }
+    ids.sort();
+
+    let order: Vec<Uuid> = proposal
+        .influence_ranking()
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert_eq!(order, ids);
+}
+
+#[test]
+fn test_acceptance_margin_flips_as_threshold_falls() {
+    let start = Utc::now();
+    let mut proposal = Proposal::new(
+        "Falling".to_string(),
+        "Threshold relaxes over time".to_string(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(-0.001, 0.9),
+    );
+    proposal.voting_window.start_time = start;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    proposal.add_vote(vote_at(VoteChoice::Yes, start));
+    proposal.add_vote(vote_at(VoteChoice::No, start));
+
+    let before = proposal.acceptance_margin(start + Duration::seconds(100));
+    assert!((before - (2.0 / 3.0 - 0.8)).abs() < 1e-9);
+    assert!(!proposal.is_passing(start + Duration::seconds(100)));
+
+    let after = proposal.acceptance_margin(start + Duration::seconds(400));
+    assert!((after - (2.0 / 3.0 - 0.5)).abs() < 1e-9);
+    assert!(proposal.is_passing(start + Duration::seconds(400)));
+}
+
+#[test]
+fn test_add_votes_bulk_reports_rejections() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+
+    let first = vote_at(VoteChoice::Yes, start + Duration::seconds(10));
+    let repeat = first.clone();
+    let mut revised = first.clone();
+    revised.revision = 1;
+    revised.choice = VoteChoice::No;
+    let early = vote_at(VoteChoice::Yes, start - Duration::seconds(10));
+    let late = vote_at(VoteChoice::Yes, start + Duration::seconds(700));
+    let other = vote_at(VoteChoice::Yes, start + Duration::seconds(20));
+    let (early_id, late_id, first_id) = (early.validator_id, late.validator_id, first.validator_id);
+
+    let report = proposal.add_votes_bulk(vec![first, repeat, early, other, late, revised]);
+
+    assert_eq!(report.accepted, 3);
+    assert_eq!(report.rejected, 3);
+    assert_eq!(
+        report.rejections,
+        vec![
+            (1, first_id, VoteError::Duplicate),
+            (2, early_id, VoteError::BeforeWindowStart),
+            (4, late_id, VoteError::AfterGracePeriod),
+        ]
+    );
+    assert_eq!(proposal.votes().len(), 2);
+}
+
+#[test]
+fn test_add_votes_bulk_checks_eligibility() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    let registered = vote_at(VoteChoice::Yes, start);
+    proposal.register_validator(registered.validator_id);
+    let stranger = vote_at(VoteChoice::No, start);
+    let stranger_id = stranger.validator_id;
+
+    let report = proposal.add_votes_bulk(vec![registered, stranger]);
+
+    assert_eq!(report.accepted, 1);
+    assert_eq!(
+        report.rejections,
+        vec![(1, stranger_id, VoteError::NotEligible)]
+    );
+}
+
+#[test]
+fn test_fork_keeps_parameters_and_resets_state() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal()
+        .with_quorum(2)
+        .with_tags(vec!["protocol".into()]);
+    proposal.voting_window.start_time = start;
+    proposal.voting_window.grace_period = 90;
+    proposal.voting_window.extend(60);
+    let voter = vote_at(VoteChoice::No, start);
+    proposal
+        .delegate(Uuid::new_v4(), voter.validator_id)
+        .unwrap();
+    proposal.add_vote(voter);
+    proposal.evaluate(start + Duration::seconds(700));
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+
+    let later = start + Duration::seconds(3600);
+    let fork = proposal.fork(later);
+
+    assert_ne!(fork.id, proposal.id);
+    assert_eq!(fork.title, proposal.title);
+    assert_eq!(fork.description, proposal.description);
+    assert_eq!(fork.decay_model, proposal.decay_model);
+    assert_eq!(fork.threshold_model, proposal.threshold_model);
+    assert_eq!(fork.quorum, Some(2));
+    assert!(fork.has_tag("protocol"));
+    assert_eq!(fork.voting_window.start_time, later);
+    assert_eq!(fork.voting_window.duration, 600);
+    assert_eq!(fork.voting_window.grace_period, 90);
+    assert_eq!(fork.voting_window.extended_by, 0);
+
+    assert_eq!(fork.status, ProposalStatus::Pending);
+    assert_eq!(fork.rejection_reason, None);
+    assert!(fork.votes().is_empty());
+    assert!(fork.delegations.is_empty());
+    assert!(fork.history.is_empty());
+    assert_eq!(fork.current_approval_ratio(), 0.0);
+}
+
+#[test]
+fn test_tally_at_window_close_ignores_grace_votes() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    proposal.add_vote(vote_at(VoteChoice::No, start));
+    let mid = vote_at(VoteChoice::Yes, start + Duration::seconds(300));
+    proposal.add_vote(mid.clone());
+    proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(610)));
+
+    assert!((proposal.current_approval_ratio() - 0.6 / 1.6).abs() < 1e-9);
+    assert!((proposal.tally_at_window_close() - 0.5 / 1.5).abs() < 1e-9);
+
+    let mut revised = mid;
+    revised.choice = VoteChoice::No;
+    revised.revision = 1;
+    revised.timestamp = start + Duration::seconds(615);
+    assert!(proposal.add_vote(revised));
+    assert!((proposal.tally_at_window_close() - 0.5 / 1.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_frozen_tally_used_by_evaluate() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal().with_frozen_tally(true);
+    proposal.voting_window.start_time = start;
+    proposal.add_vote(vote_at(VoteChoice::No, start));
+    proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(610)));
+
+    let outcome = proposal.evaluate_detailed(start + Duration::seconds(620));
+
+    assert_eq!(outcome.status, ProposalStatus::Rejected);
+    assert_eq!(outcome.approval_ratio, 0.0);
+    assert!(proposal.current_approval_ratio() > 0.0);
+}
+
+#[test]
+fn test_minor_amendment_carries_votes_over() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(10)));
+    proposal.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(20)));
+    let ratio = proposal.current_approval_ratio();
+
+    let at = start + Duration::seconds(30);
+    assert!(proposal.amend("Fixed a typo".into(), false, at));
+    proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(40)));
+
+    assert_eq!(proposal.description, "Fixed a typo");
+    assert_eq!(proposal.votes().len(), 3);
+    let flags: Vec<bool> = proposal.votes().iter().map(|v| v.amended_after).collect();
+    assert_eq!(flags, vec![true, true, false]);
+    assert_eq!(proposal.amendments.len(), 1);
+    assert_eq!(proposal.amendments[0].at, at);
+    assert_eq!(proposal.amendments[0].previous_description, "Desc");
+    assert!(!proposal.amendments[0].votes_invalidated);
+    assert!(proposal.current_approval_ratio() > ratio);
+}
+
+#[test]
+fn test_substantive_amendment_invalidates_votes() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    let voter = vote_at(VoteChoice::Yes, start + Duration::seconds(10));
+    proposal.add_vote(voter.clone());
+    proposal.add_vote(Vote {
+        revision: 1,
+        ..voter.clone()
+    });
+
+    assert!(proposal.amend("New budget".into(), true, start + Duration::seconds(30)));
+    assert!(proposal.votes().is_empty());
+    assert!(proposal.previous_choices(voter.validator_id).is_empty());
+    assert_eq!(proposal.current_approval_ratio(), 0.0);
+    assert!(proposal.amendments[0].votes_invalidated);
+
+    assert!(proposal.add_vote(Vote {
+        choice: VoteChoice::No,
+        ..voter
+    }));
+    assert_eq!(proposal.current_approval_ratio(), 0.0);
+
+    proposal.status = ProposalStatus::Accepted;
+    assert!(!proposal.amend("Too late".into(), true, start + Duration::seconds(60)));
+    assert_eq!(proposal.amendments.len(), 1);
+}
+
+#[test]
+fn test_substantive_amendment_discards_commitments_and_delegations() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    let (validator, delegate) = (Uuid::new_v4(), Uuid::new_v4());
+    proposal
+        .commit(
+            VoteCommitment {
+                validator_id: validator,
+                commitment: commitment_hash(&VoteChoice::Yes, "s3cret"),
+                timestamp: start,
+                stake: 1,
+            },
+            start,
+        )
+        .unwrap();
+    proposal.delegate(validator, delegate).unwrap();
+
+    assert!(proposal.amend("New budget".into(), true, start + Duration::seconds(30)));
+    assert!(proposal.commitments.is_empty());
+    assert!(proposal.delegations.is_empty());
+    assert_eq!(
+        proposal.reveal(
+            validator,
+            VoteChoice::Yes,
+            "s3cret",
+            start + Duration::seconds(610)
+        ),
+        Err(VoteError::NoCommitment)
+    );
+    assert!(proposal.votes().is_empty());
+}
+
+fn boundary_proposal(start: chrono::DateTime<Utc>) -> Proposal {
+    // 5s * 0.01 + 0.65 rounds to 0.7000000000000001, just above a 7:3 split
+    let mut proposal = Proposal::new(
+        "Boundary".into(),
+        "Desc".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.01, 0.65),
+    );
+    proposal.voting_window.start_time = start;
+    for (choice, stake) in [(VoteChoice::Yes, 7), (VoteChoice::No, 3)] {
+        proposal.add_vote(Vote {
+            stake,
+            ..vote_at(choice, start)
+        });
+    }
+    proposal
+}
+
+#[test]
+fn test_ratio_equal_to_threshold_is_accepted() {
+    let start = Utc::now();
+    let now = start + Duration::seconds(5);
+    let mut proposal = boundary_proposal(start);
+    assert_eq!(proposal.current_approval_ratio(), 0.7);
+    assert!(proposal.acceptance_margin(now) < 0.0);
+    assert_eq!(proposal.threshold_epsilon, DEFAULT_THRESHOLD_EPSILON);
+    assert!(proposal.is_passing(now));
+
+    proposal.evaluate(now);
+    assert_eq!(proposal.status, ProposalStatus::Accepted);
+}
+
+#[test]
+fn test_zero_epsilon_makes_threshold_strict() {
+    let start = Utc::now();
+    let now = start + Duration::seconds(5);
+    let mut proposal = boundary_proposal(start).with_threshold_epsilon(0.0);
+    assert!(!proposal.is_passing(now));
+
+    proposal.evaluate(now);
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+}
+
+#[test]
+fn test_delegation_requires_registered_validators() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    let (voter, member, outsider) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    proposal.register_validator(voter);
+    proposal.register_validator(member);
+    proposal.add_vote(Vote {
+        validator_id: voter,
+        ..vote_at(VoteChoice::Yes, start)
+    });
+
+    assert_eq!(
+        proposal.delegate(outsider, voter),
+        Err(VoteError::NotEligible)
+    );
+    assert_eq!(
+        proposal.delegate(member, outsider),
+        Err(VoteError::NotEligible)
+    );
+    assert!(proposal.delegations.is_empty());
+
+    proposal.delegate(member, voter).unwrap();
+    assert_eq!(proposal.effective_participation(), 2);
+    assert_eq!(proposal.weighted_tally().total_validators, 2);
+}
+
+#[test]
+fn test_delegation_lapses_when_registry_excludes_delegator() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    let (voter, outsider) = (Uuid::new_v4(), Uuid::new_v4());
+    proposal.add_vote(Vote {
+        validator_id: voter,
+        ..vote_at(VoteChoice::Yes, start)
+    });
+    proposal.delegate(outsider, voter).unwrap();
+    assert_eq!(proposal.effective_participation(), 2);
+
+    proposal.register_validator(voter);
+    assert_eq!(proposal.effective_participation(), 1);
+    assert_eq!(proposal.weighted_tally().total_validators, 1);
+}
+
+#[test]
+fn test_pause_does_not_decay_votes() {
+    let start = Utc::now();
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    let before = vote_at(VoteChoice::Yes, start + Duration::seconds(100));
+    proposal.add_vote(before.clone());
+    let weight_before = proposal
+        .snapshot(start + Duration::seconds(100))
+        .vote_weights[0]
+        .1;
+
+    // A hold as long as the whole window
+    proposal.voting_window.pause(start + Duration::seconds(100));
+    let during = vote_at(VoteChoice::No, start + Duration::seconds(400));
+    proposal.add_vote(during);
+    proposal
+        .voting_window
+        .resume(start + Duration::seconds(700));
+    let after = vote_at(VoteChoice::No, start + Duration::seconds(700));
+    proposal.add_vote(after);
+
+    let weights: Vec<f64> = proposal
+        .snapshot(start + Duration::seconds(700))
+        .vote_weights
+        .iter()
+        .map(|(_, w)| *w)
+        .collect();
+    assert_eq!(weights[0], weight_before);
+    assert_eq!(weights[1], weight_before);
+    assert_eq!(weights[2], weight_before);
+    assert!(weight_before > 0.8);
+    assert_eq!(
+        proposal.voting_window.state(start + Duration::seconds(400)),
+        WindowState::Open
+    );
+}
+
+#[test]
+fn test_commit_refused_after_window_closes() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    let commitment = VoteCommitment {
+        validator_id: Uuid::new_v4(),
+        commitment: commitment_hash(&VoteChoice::Yes, "s3cret"),
+        timestamp: start,
+        stake: 1,
+    };
+
+    assert_eq!(
+        proposal.commit(commitment.clone(), start + Duration::seconds(610)),
+        Err(VoteError::CommitPhaseOver)
+    );
+    assert_eq!(
+        proposal.commit(commitment.clone(), start - Duration::seconds(10)),
+        Err(VoteError::BeforeWindowStart)
+    );
+    assert!(proposal.commitments.is_empty());
+    assert!(
+        proposal
+            .commit(commitment, start + Duration::seconds(590))
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_reveal_refused_outside_grace_period() {
+    let mut proposal = delegation_proposal();
+    let start = proposal.voting_window.start_time;
+    let validator = Uuid::new_v4();
+    proposal
+        .commit(
+            VoteCommitment {
+                validator_id: validator,
+                commitment: commitment_hash(&VoteChoice::Yes, "s3cret"),
+                timestamp: start,
+                stake: 1,
+            },
+            start,
+        )
+        .unwrap();
+
+    assert_eq!(
+        proposal.reveal(
+            validator,
+            VoteChoice::Yes,
+            "s3cret",
+            start + Duration::seconds(300)
+        ),
+        Err(VoteError::RevealNotOpen)
+    );
+    assert_eq!(
+        proposal.reveal(
+            validator,
+            VoteChoice::Yes,
+            "s3cret",
+            start + Duration::seconds(700)
+        ),
+        Err(VoteError::AfterGracePeriod)
+    );
+    assert!(proposal.votes().is_empty());
+    assert!(proposal.commitments.contains_key(&validator));
+}
+
+#[test]
+fn test_velocity_extension_refreshes_cached_ratio() {
+    let start = Utc::now();
+    let policy = VelocityPolicy {
+        extension_seconds: 600,
+        window_seconds: 30,
+        min_velocity: 0.1,
+        time_proximity: 0.9,
+    };
+    let mut proposal = delegation_proposal();
+    proposal.voting_window.start_time = start;
+    proposal.add_vote(vote_at(VoteChoice::Yes, start + Duration::seconds(10)));
+    for offset in [555, 560, 570, 575] {
+        proposal.add_vote(vote_at(VoteChoice::No, start + Duration::seconds(offset)));
+    }
+    let before = proposal.current_approval_ratio();
+
+    assert!(proposal.extend_on_velocity(start + Duration::seconds(580), policy));
+
+    let mut fresh = proposal.clone();
+    fresh.invalidate_weight_cache();
+    assert_eq!(
+        proposal.current_approval_ratio(),
+        fresh.current_approval_ratio()
+    );
+    assert!(
+        (proposal.current_approval_ratio() - proposal.weighted_tally().approval_ratio).abs()
+            < 1e-12
+    );
+    assert_ne!(proposal.current_approval_ratio(), before);
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "clock")]
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use verdyce_core::decay::DecayModel;
+use verdyce_core::engine::Engine;
+use verdyce_core::models::{
+    proposal::Proposal,
+    vote::{Vote, VoteChoice},
+};
+use verdyce_core::storage::{InMemoryStore, IntegrityError, ProposalStore, SealedProposal};
+use verdyce_core::threshold::ThresholdModel;
+
+fn proposal_with_vote(decay: DecayModel, threshold: ThresholdModel) -> Proposal {
+    let mut proposal = Proposal::new("Stored".into(), "Description".into(), 600, decay, threshold);
+    proposal.add_vote(Vote {
+        validator_id: Uuid::new_v4(),
+        choice: VoteChoice::Yes,
+        timestamp: Utc::now() - Duration::seconds(5),
+        revision: 0,
+        reason: Some("test".into()),
+        stake: 3,
+        cast_during_grace: false,
+        amended_after: false,
+    });
+    proposal
+}
+
+#[test]
+fn test_in_memory_store_round_trip() {
+    let mut store = InMemoryStore::new();
+    let proposal = proposal_with_vote(
+        DecayModel::Exponential(0.01),
+        ThresholdModel::Sigmoid(4.0, 0.3),
+    );
+    let id = proposal.id;
+
+    store.save(&proposal);
+    let loaded = store.load(id).unwrap();
+
+    assert_eq!(loaded.id, id);
+    assert!(matches!(loaded.decay_model, DecayModel::Exponential(r) if r == 0.01));
+    assert_eq!(loaded.threshold_model, ThresholdModel::Sigmoid(4.0, 0.3));
+    assert_eq!(loaded.votes(), proposal.votes());
+
+    store.delete(id);
+    assert!(store.load(id).is_none());
+    assert!(store.load_all().is_empty());
+}
+
+#[test]
+fn test_engine_persist_all_and_reload() {
+    let mut engine = Engine::with_store(Box::new(InMemoryStore::new()));
+    engine.add_proposal(proposal_with_vote(
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    ));
+    engine.add_proposal(proposal_with_vote(
+        DecayModel::Stepped,
+        ThresholdModel::Exponential(0.01, 0.4),
+    ));
+    engine.persist_all();
+
+    let stored = engine.store().unwrap().load_all();
+    assert_eq!(stored.len(), 2);
+    for proposal in &engine.proposals {
+        let loaded = engine.store().unwrap().load(proposal.id).unwrap();
+        assert_eq!(loaded.threshold_model, proposal.threshold_model);
+        assert_eq!(loaded.votes().len(), 1);
+    }
+
+    let mut store = InMemoryStore::new();
+    for proposal in &engine.proposals {
+        store.save(proposal);
+    }
+    let reloaded = Engine::with_store(Box::new(store));
+    assert_eq!(reloaded.proposals.len(), 2);
+}
+
+#[test]
+fn test_sealed_proposal_round_trip() {
+    let proposal = proposal_with_vote(DecayModel::Linear, ThresholdModel::Linear(0.0, 0.5));
+
+    let stored = SealedProposal::seal(&proposal).unwrap().to_json().unwrap();
+    let loaded = SealedProposal::from_json(&stored).unwrap();
+
+    assert_eq!(loaded.id, proposal.id);
+    assert_eq!(loaded.votes(), proposal.votes());
+    assert_eq!(
+        SealedProposal::seal(&loaded).unwrap().to_json().unwrap(),
+        stored
+    );
+}
+
+#[test]
+fn test_sealed_proposal_detects_tampering() {
+    let proposal = proposal_with_vote(DecayModel::Linear, ThresholdModel::Linear(0.0, 0.5));
+    let mut sealed = SealedProposal::seal(&proposal).unwrap();
+    sealed.payload = sealed.payload.replace("\"stake\":3", "\"stake\":300");
+
+    let err = SealedProposal::from_json(&sealed.to_json().unwrap()).unwrap_err();
+    assert!(matches!(err, IntegrityError::ChecksumMismatch { .. }));
+
+    let err = SealedProposal::from_json("{\"checksum\":").unwrap_err();
+    assert!(matches!(err, IntegrityError::Corrupted(_)));
+}
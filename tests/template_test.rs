@@ -0,0 +1,69 @@
+#![cfg(feature = "clock")]
+
+use chrono::Utc;
+use verdyce_core::decay::DecayModel;
+use verdyce_core::models::proposal::ProposalStatus;
+use verdyce_core::models::template::ProposalTemplate;
+use verdyce_core::models::vote::AbstainMode;
+use verdyce_core::threshold::ThresholdModel;
+use verdyce_core::window::DEFAULT_GRACE_PERIOD;
+
+const TREASURY_TEMPLATE: &str = r#"{
+    "duration": 86400,
+    "grace_period": 600,
+    "decay_model": {"SteppedN": 4},
+    "threshold_model": {"ShiftedSigmoid": {"rate": 8.0, "floor": 0.5, "midpoint": 0.75}},
+    "quorum": 5,
+    "min_threshold": 0.6,
+    "abstain_mode": "CountInDenominator",
+    "extension_policy": {
+        "extension_seconds": 3600,
+        "threshold_proximity": 0.9,
+        "time_proximity": 0.95
+    },
+    "tags": ["treasury"]
+}"#;
+
+#[test]
+fn test_template_instantiates_configured_proposal() {
+    let template = ProposalTemplate::from_json(TREASURY_TEMPLATE).unwrap();
+    let now = Utc::now();
+
+    let proposal = template.instantiate_at("Grant", "Fund the audit", now);
+
+    assert_eq!(proposal.title, "Grant");
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+    assert_eq!(proposal.voting_window.start_time, now);
+    assert_eq!(proposal.voting_window.duration, 86400);
+    assert_eq!(proposal.voting_window.grace_period, 600);
+    assert_eq!(proposal.decay_model, DecayModel::SteppedN(4));
+    assert_eq!(
+        proposal.threshold_model,
+        ThresholdModel::ShiftedSigmoid {
+            rate: 8.0,
+            floor: 0.5,
+            midpoint: 0.75
+        }
+    );
+    assert_eq!(proposal.quorum, Some(5));
+    assert_eq!(proposal.min_threshold, 0.6);
+    assert_eq!(proposal.abstain_mode, AbstainMode::CountInDenominator);
+    assert_eq!(proposal.extension_policy.unwrap().max_extensions, None);
+    assert!(proposal.has_tag("treasury"));
+}
+
+#[test]
+fn test_template_defaults_optional_settings() {
+    let template = ProposalTemplate::from_json(
+        r#"{"duration": 600, "decay_model": "Linear", "threshold_model": {"Linear": [0.0, 0.5]}}"#,
+    )
+    .unwrap();
+
+    let proposal = template.instantiate("Minimal", "");
+
+    assert_eq!(proposal.voting_window.grace_period, DEFAULT_GRACE_PERIOD);
+    assert_eq!(proposal.threshold_model, ThresholdModel::Linear(0.0, 0.5));
+    assert_eq!(proposal.quorum, None);
+    assert!(proposal.extension_policy.is_none());
+    assert!(ProposalTemplate::from_json(r#"{"duration": 600}"#).is_err());
+}
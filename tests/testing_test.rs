@@ -0,0 +1,64 @@
+#![cfg(feature = "testing")]
+
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use verdyce_core::decay::DecayModel;
+use verdyce_core::engine::Engine;
+use verdyce_core::models::{
+    proposal::{Proposal, ProposalStatus},
+    vote::{Vote, VoteChoice},
+};
+use verdyce_core::storage::ProposalStore;
+use verdyce_core::testing::{InMemoryEngine, MockStore};
+use verdyce_core::threshold::ThresholdModel;
+
+fn yes_vote() -> Vote {
+    Vote {
+        validator_id: Uuid::new_v4(),
+        choice: VoteChoice::Yes,
+        timestamp: Utc::now(),
+        revision: 0,
+        reason: None,
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
+    }
+}
+
+#[test]
+fn test_create_vote_evaluate_in_memory() {
+    let mut engine = InMemoryEngine::new();
+    let proposal = Proposal::new(
+        "Offline".into(),
+        "Runs without a database".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    );
+    let id = engine.create_proposal(proposal);
+    assert_eq!(engine.load(id).unwrap().status, ProposalStatus::Pending);
+
+    engine.cast_vote(id, yes_vote()).unwrap();
+    assert_eq!(engine.load(id).unwrap().votes().len(), 1);
+
+    engine.evaluate(Utc::now() + Duration::seconds(1));
+    assert_eq!(engine.load(id).unwrap().status, ProposalStatus::Accepted);
+    assert!(engine.cast_vote(id, yes_vote()).is_err());
+}
+
+#[test]
+fn test_mock_store_is_shared_between_clones() {
+    let store = MockStore::new();
+    let mut engine = Engine::with_store(Box::new(store.clone()));
+    engine.add_proposal(Proposal::new(
+        "Shared".into(),
+        "".into(),
+        600,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    ));
+    engine.persist_all();
+
+    assert_eq!(store.save_count(), 1);
+    assert_eq!(store.load_all().len(), 1);
+}
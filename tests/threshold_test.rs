@@ -1,4 +1,8 @@
-use verdyce_core::threshold::{ThresholdModel, threshold_calc};
+use verdyce_core::threshold::{
+    ClampPolicy, ThresholdError, ThresholdModel, is_clamped, threshold_calc,
+    threshold_calc_bounded, threshold_calc_checked, threshold_calc_raw,
+    threshold_calc_with_participation, threshold_slope,
+};
 
 #[test]
 fn test_linear_threshold() {
@@ -31,3 +35,231 @@ fn test_sigmoid_threshold() {
     let expected = 0.1 + (1.0 - 0.1) * sigmoid;
     assert!((thres - expected).abs() < 0.001)
 }
+
+#[test]
+fn test_bounded_threshold_can_reach_upper_bound() {
+    let model = ThresholdModel::Linear(0.001, 0.5);
+    let thres = threshold_calc_bounded(&model, 600, 600, 0.35, 0.95).unwrap();
+    assert!((thres - 0.95).abs() < 0.001);
+    assert!((threshold_calc(&model, 600, 600) - 0.9).abs() < 0.001);
+}
+
+#[test]
+fn test_bounded_threshold_respects_floor() {
+    let model = ThresholdModel::Linear(0.0, 0.4);
+    let thres = threshold_calc_bounded(&model, 10, 100, 0.5, 0.9).unwrap();
+    assert!((thres - 0.5).abs() < 0.001);
+}
+
+#[test]
+fn test_default_bounds_preserved() {
+    let model = ThresholdModel::Sigmoid(4.0, 0.2);
+    for t in [0, 25, 50, 75, 100] {
+        let expected = threshold_calc_bounded(&model, t, 100, 0.35, 0.9);
+        assert_eq!(Ok(threshold_calc(&model, t, 100)), expected);
+    }
+}
+
+#[test]
+fn test_bounded_threshold_rejects_invalid_bounds() {
+    let model = ThresholdModel::Linear(0.0, 0.5);
+    assert_eq!(
+        threshold_calc_bounded(&model, 0, 100, 0.9, 0.5),
+        Err(ThresholdError::InvalidBounds { lo: 0.9, hi: 0.5 })
+    );
+    assert!(threshold_calc_bounded(&model, 0, 100, 0.35, 1.5).is_err());
+}
+
+fn participation_model() -> ThresholdModel {
+    ThresholdModel::TimeAndParticipation {
+        base: Box::new(ThresholdModel::Linear(0.0, 0.5)),
+        participation_weight: 0.3,
+    }
+}
+
+#[test]
+fn test_low_participation_raises_threshold() {
+    let model = participation_model();
+    let low = threshold_calc_with_participation(&model, 50, 100, 0.2);
+    let full = threshold_calc_with_participation(&model, 50, 100, 1.0);
+    assert!(low > full);
+    assert!((low - 0.74).abs() < 0.001);
+    assert!((full - 0.5).abs() < 0.001);
+}
+
+#[test]
+fn test_participation_ignored_by_time_only_models() {
+    let model = ThresholdModel::Linear(0.001, 0.5);
+    let thres = threshold_calc_with_participation(&model, 50, 100, 0.0);
+    assert_eq!(thres, threshold_calc(&model, 50, 100));
+}
+
+fn finite_difference(model: &ThresholdModel, t: u64, total: u64) -> f64 {
+    (threshold_calc(model, t + 1, total) - threshold_calc(model, t - 1, total)) / 2.0
+}
+
+#[test]
+fn test_slope_matches_finite_difference() {
+    let models = [
+        ThresholdModel::Linear(0.0001, 0.5),
+        ThresholdModel::Exponential(0.001, 0.4),
+        ThresholdModel::Sigmoid(4.0, 0.3),
+    ];
+    for model in &models {
+        let slope = threshold_slope(model, 500, 1000);
+        let numeric = finite_difference(model, 500, 1000);
+        assert!(slope > 0.0);
+        assert!((slope - numeric).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_slope_is_zero_when_clamped() {
+    let model = ThresholdModel::Linear(0.01, 0.5);
+    assert_eq!(threshold_slope(&model, 100, 1000), 0.0);
+    assert!((threshold_slope(&model, 10, 1000) - 0.01).abs() < 1e-12);
+}
+
+#[test]
+fn test_validate_rejects_bad_parameters() {
+    assert_eq!(
+        ThresholdModel::Sigmoid(0.0, 0.3).validate(),
+        Err(ThresholdError::ZeroRate)
+    );
+    assert_eq!(
+        ThresholdModel::Linear(-0.1, 0.5).validate(),
+        Err(ThresholdError::NegativeRate(-0.1))
+    );
+    assert_eq!(
+        ThresholdModel::Exponential(0.1, 1.5).validate(),
+        Err(ThresholdError::StartOutOfRange(1.5))
+    );
+    assert_eq!(
+        ThresholdModel::Sigmoid(2.0, -0.2).validate(),
+        Err(ThresholdError::StartOutOfRange(-0.2))
+    );
+    let nested = ThresholdModel::TimeAndParticipation {
+        base: Box::new(ThresholdModel::Sigmoid(0.0, 0.5)),
+        participation_weight: 0.2,
+    };
+    assert_eq!(nested.validate(), Err(ThresholdError::ZeroRate));
+}
+
+#[test]
+fn test_validate_accepts_constant_linear() {
+    assert!(ThresholdModel::Linear(0.0, 0.5).validate().is_ok());
+    assert!(ThresholdModel::Sigmoid(4.0, 0.2).validate().is_ok());
+}
+
+#[test]
+fn test_threshold_sample_endpoints_match_threshold_calc() {
+    let model = ThresholdModel::Sigmoid(8.0, 0.4);
+    let samples = model.sample(7200, 10);
+
+    assert_eq!(samples.len(), 10);
+    assert_eq!(samples[0], (0, threshold_calc(&model, 0, 7200)));
+    assert_eq!(samples[9], (7200, threshold_calc(&model, 7200, 7200)));
+    assert!(samples.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn test_threshold_sample_with_zero_points_returns_endpoints() {
+    let samples = ThresholdModel::Linear(0.5, 0.4).sample(100, 0);
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[1].0, 100);
+}
+
+#[test]
+fn test_checked_threshold_clamps_overflow_by_default() {
+    let model = ThresholdModel::Linear(0.0005, 0.5);
+    let clamped = threshold_calc_checked(&model, 3000, 3600, ClampPolicy::default()).unwrap();
+    assert_eq!(clamped, threshold_calc(&model, 3000, 3600));
+    assert!((clamped - 0.9).abs() < 1e-12);
+}
+
+#[test]
+fn test_checked_threshold_reports_overflow() {
+    let model = ThresholdModel::Linear(0.0005, 0.5);
+    let result = threshold_calc_checked(&model, 3000, 3600, ClampPolicy::ErrorOnOverflow);
+    match result {
+        Err(ThresholdError::OutOfBounds(raw)) => assert!((raw - 2.0).abs() < 1e-9),
+        other => panic!("expected OutOfBounds, got {other:?}"),
+    }
+
+    let in_range = threshold_calc_checked(&model, 100, 3600, ClampPolicy::ErrorOnOverflow);
+    assert!((in_range.unwrap() - 0.55).abs() < 1e-9);
+}
+
+#[test]
+fn test_zero_total_gives_start_threshold() {
+    let sigmoid = ThresholdModel::Sigmoid(6.0, 0.6);
+    assert_eq!(threshold_calc(&sigmoid, 0, 0), 0.6);
+    assert_eq!(threshold_slope(&sigmoid, 0, 0), 0.0);
+
+    let linear = ThresholdModel::Linear(0.01, 0.5);
+    assert_eq!(threshold_calc(&linear, 100, 0), 0.5);
+}
+
+#[test]
+fn test_shifted_sigmoid_inflects_at_midpoint() {
+    let model = ThresholdModel::ShiftedSigmoid {
+        rate: 10.0,
+        floor: 0.3,
+        midpoint: 0.75,
+    };
+    let total = 1000;
+
+    // Half-way between floor and 1.0 exactly at 75% of the window
+    assert!((threshold_calc(&model, 750, total) - 0.65).abs() < 1e-9);
+
+    let steepest = threshold_slope(&model, 750, total);
+    for t in [500, 700, 800] {
+        assert!(threshold_slope(&model, t, total) < steepest, "t = {t}");
+    }
+}
+
+#[test]
+fn test_shifted_sigmoid_defaults_match_sigmoid() {
+    let shifted = ThresholdModel::ShiftedSigmoid {
+        rate: 6.0,
+        floor: 0.4,
+        midpoint: 0.5,
+    };
+    let plain = ThresholdModel::Sigmoid(6.0, 0.4);
+    for t in [0, 250, 500, 750, 1000] {
+        assert_eq!(
+            threshold_calc(&shifted, t, 1000),
+            threshold_calc(&plain, t, 1000)
+        );
+    }
+
+    let out_of_range = ThresholdModel::ShiftedSigmoid {
+        rate: 6.0,
+        floor: 0.4,
+        midpoint: 3.0,
+    };
+    let clamped = ThresholdModel::ShiftedSigmoid {
+        rate: 6.0,
+        floor: 0.4,
+        midpoint: 1.0,
+    };
+    assert_eq!(
+        threshold_calc(&out_of_range, 400, 1000),
+        threshold_calc(&clamped, 400, 1000)
+    );
+}
+
+#[test]
+fn test_raw_threshold_exceeds_clamp() {
+    let model = ThresholdModel::Exponential(0.01, 0.6);
+    let total = 3600;
+
+    let raw = threshold_calc_raw(&model, total, total);
+    assert!(raw > 0.9);
+    assert_eq!(threshold_calc(&model, total, total), 0.9);
+    assert!(is_clamped(&model, total, total));
+
+    let early = threshold_calc_raw(&model, 10, total);
+    assert_eq!(threshold_calc(&model, 10, total), early);
+    assert!(!is_clamped(&model, 10, total));
+}
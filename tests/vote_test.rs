@@ -1,7 +1,12 @@
+#![cfg(feature = "clock")]
+
 use chrono::{Duration, Utc};
 use uuid::Uuid;
 use verdyce_core::decay::DecayModel;
-use verdyce_core::models::vote::{Vote, VoteChoice, calculate_vote_weight};
+use verdyce_core::models::vote::{
+    RevisionPenalty, Vote, VoteChoice, calculate_vote_weight, calculate_vote_weight_with_penalty,
+    commitment_hash,
+};
 
 #[test]
 fn test_vote_at_start_no_revision() {
@@ -12,6 +17,9 @@ fn test_vote_at_start_no_revision() {
         timestamp: now,
         revision: 0,
         reason: None,
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
     };
 
     let model = DecayModel::Linear;
@@ -29,6 +37,9 @@ fn test_vote_halfway_with_revision() {
         timestamp: now,
         revision: 1,
         reason: Some("Changed mind".to_string()),
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
     };
     let model = DecayModel::Linear;
     let weight = calculate_vote_weight(&vote, proposal_start, 1800, &model);
@@ -46,6 +57,9 @@ fn test_vote_near_expiry_high_revision() {
         timestamp,
         revision: 3,
         reason: Some("Unstable".to_string()),
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
     };
 
     let model = DecayModel::Linear;
@@ -53,3 +67,72 @@ fn test_vote_near_expiry_high_revision() {
     // Without penalty: ~0.055, penalty: /16 = ~0.0034375, floored to 0.1
     assert!((weight - 0.1).abs() < 0.001);
 }
+
+#[test]
+fn test_stake_defaults_to_one_when_missing() {
+    let json = r#"{
+        "validator_id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "choice": "Yes",
+        "timestamp": "2025-01-01T00:00:00Z",
+        "revision": 0,
+        "reason": null
+    }"#;
+    let vote: Vote = serde_json::from_str(json).unwrap();
+    assert_eq!(vote.stake, 1);
+}
+
+fn revised_vote(start: chrono::DateTime<Utc>) -> Vote {
+    Vote {
+        validator_id: Uuid::new_v4(),
+        choice: VoteChoice::Yes,
+        timestamp: start + Duration::seconds(360),
+        revision: 2,
+        reason: None,
+        stake: 1,
+        cast_during_grace: false,
+        amended_after: false,
+    }
+}
+
+#[test]
+fn test_revision_penalty_none_uses_decay_only() {
+    let start = Utc::now();
+    let vote = revised_vote(start);
+    let weight = calculate_vote_weight_with_penalty(
+        &vote,
+        start,
+        1800,
+        &DecayModel::Linear,
+        RevisionPenalty::None,
+    );
+    assert!((weight - 0.8).abs() < 0.001);
+}
+
+#[test]
+fn test_revision_penalty_linear_divides_by_revisions() {
+    let start = Utc::now();
+    let vote = revised_vote(start);
+    let weight = calculate_vote_weight_with_penalty(
+        &vote,
+        start,
+        1800,
+        &DecayModel::Linear,
+        RevisionPenalty::Linear,
+    );
+    assert!((weight - 0.8 / 3.0).abs() < 0.001);
+
+    let quadratic = calculate_vote_weight(&vote, start, 1800, &DecayModel::Linear);
+    assert!((quadratic - 0.1).abs() < 0.001);
+}
+
+#[test]
+fn test_commitment_hash_is_stable_hex() {
+    let hash = commitment_hash(&VoteChoice::Yes, "nonce");
+    assert_eq!(hash.len(), 64);
+    assert!(
+        hash.chars()
+            .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase())
+    );
+    assert_eq!(hash, commitment_hash(&VoteChoice::Yes, "nonce"));
+    assert_ne!(hash, commitment_hash(&VoteChoice::No, "nonce"));
+}
@@ -1,5 +1,12 @@
-use chrono::{Duration, Utc};
-use verdyce_core::window::{VotingWindow, WindowState};
+#![cfg(feature = "clock")]
+
+use chrono::{Duration, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use chrono_tz::Asia::Tokyo;
+use verdyce_core::decay::DecayModel;
+use verdyce_core::models::proposal::{Proposal, ProposalStatus};
+use verdyce_core::threshold::ThresholdModel;
+use verdyce_core::window::{VotingPhase, VotingWindow, WindowState};
 
 #[test]
 fn test_not_started_state() {
@@ -21,3 +28,254 @@ fn test_expired_state() {
     let window = VotingWindow::new(start, 60, 50);
     assert_eq!(window.state(Utc::now()), WindowState::Expired);
 }
+
+#[test]
+fn test_remaining_and_progress_not_started() {
+    let now = Utc::now();
+    let window = VotingWindow::new(now + Duration::seconds(60), 120, 30);
+    assert_eq!(window.remaining(now), 150);
+    assert_eq!(window.progress(now), 0.0);
+}
+
+#[test]
+fn test_remaining_and_progress_open() {
+    let now = Utc::now();
+    let window = VotingWindow::new(now - Duration::seconds(30), 120, 30);
+    assert_eq!(window.state(now), WindowState::Open);
+    assert_eq!(window.remaining(now), 120);
+    assert!((window.progress(now) - 0.25).abs() < 1e-9);
+}
+
+#[test]
+fn test_remaining_and_progress_grace_and_expired() {
+    let now = Utc::now();
+    let grace = VotingWindow::new(now - Duration::seconds(130), 120, 30);
+    assert_eq!(grace.state(now), WindowState::GracePeriod);
+    assert_eq!(grace.remaining(now), 20);
+    assert_eq!(grace.progress(now), 1.0);
+
+    let expired = VotingWindow::new(now - Duration::seconds(200), 120, 30);
+    assert_eq!(expired.state(now), WindowState::Expired);
+    assert_eq!(expired.remaining(now), 0);
+    assert_eq!(expired.progress(now), 1.0);
+}
+
+#[test]
+fn test_extension_cap_refuses_further_extensions() {
+    let mut window = VotingWindow::new(Utc::now(), 120, 30).with_max_extensions(2);
+    assert!(window.extend(30));
+    assert!(window.extend(30));
+    assert!(!window.extend(30));
+    assert_eq!(window.extended_by, 60);
+    assert_eq!(window.extensions, 2);
+
+    let mut unlimited = VotingWindow::new(Utc::now(), 120, 30);
+    for _ in 0..20 {
+        assert!(unlimited.extend(10));
+    }
+}
+
+#[test]
+fn test_pause_excludes_paused_span_from_elapsed() {
+    let start = Utc::now();
+    let mut window = VotingWindow::new(start, 120, 30);
+
+    window.pause(start + Duration::seconds(40));
+    assert!(window.is_paused());
+    assert_eq!(window.elapsed(start + Duration::seconds(100)), 40);
+
+    window.resume(start + Duration::seconds(340));
+    assert!(!window.is_paused());
+    assert_eq!(window.paused_total, 300);
+    assert_eq!(window.elapsed(start + Duration::seconds(350)), 50);
+    assert_eq!(
+        window.state(start + Duration::seconds(350)),
+        WindowState::Open
+    );
+}
+
+#[test]
+fn test_repeated_pause_and_resume_are_no_ops() {
+    let start = Utc::now();
+    let mut window = VotingWindow::new(start, 120, 30);
+
+    window.resume(start + Duration::seconds(10));
+    assert_eq!(window.paused_total, 0);
+
+    window.pause(start + Duration::seconds(10));
+    window.pause(start + Duration::seconds(20));
+    window.resume(start + Duration::seconds(30));
+    assert_eq!(window.paused_total, 20);
+}
+
+#[test]
+fn test_window_from_end_time() {
+    let start = Utc::now();
+    let end = start + Duration::hours(2);
+    let mut window = VotingWindow::with_end_time(start, end, 30);
+
+    assert_eq!(window.duration, 7200);
+    assert_eq!(window.end_time(), end);
+
+    window.extend(600);
+    assert_eq!(window.end_time(), end + Duration::seconds(600));
+}
+
+#[test]
+fn test_window_end_before_start_clamps_to_zero() {
+    let start = Utc::now();
+    let window = VotingWindow::with_end_time(start, start - Duration::seconds(10), 30);
+    assert_eq!(window.duration, 0);
+    assert_eq!(window.end_time(), start);
+}
+
+#[test]
+fn test_phase_from_elapsed_time() {
+    let start = Utc::now();
+    let window = VotingWindow::new(start, 90, 30);
+    assert_eq!(
+        window.phase(start + Duration::seconds(10)),
+        VotingPhase::Early
+    );
+    assert_eq!(
+        window.phase(start + Duration::seconds(30)),
+        VotingPhase::Early
+    );
+    assert_eq!(
+        window.phase(start + Duration::seconds(45)),
+        VotingPhase::Mid
+    );
+    assert_eq!(
+        window.phase(start + Duration::seconds(80)),
+        VotingPhase::Late
+    );
+}
+
+#[test]
+fn test_phase_fraction_ranges() {
+    let phases = [VotingPhase::Early, VotingPhase::Mid, VotingPhase::Late];
+    assert_eq!(phases[0].as_fraction_range().0, 0.0);
+    assert_eq!(phases[2].as_fraction_range().1, 1.0);
+    for pair in phases.windows(2) {
+        assert_eq!(pair[0].as_fraction_range().1, pair[1].as_fraction_range().0);
+    }
+
+    let json = serde_json::to_string(&VotingPhase::Mid).unwrap();
+    assert_eq!(json, "\"Mid\"");
+    assert_eq!(
+        serde_json::from_str::<VotingPhase>(&json).unwrap(),
+        VotingPhase::Mid
+    );
+}
+
+#[test]
+fn test_end_time_in_named_time_zones() {
+    let start = Utc.with_ymd_and_hms(2025, 7, 1, 14, 0, 0).unwrap();
+    let mut window = VotingWindow::new(start, 3600, 30);
+
+    let new_york = window.end_time_in_tz(&New_York);
+    assert_eq!(new_york.naive_local().to_string(), "2025-07-01 11:00:00");
+    let tokyo = window.end_time_in_tz(&Tokyo);
+    assert_eq!(tokyo.naive_local().to_string(), "2025-07-02 00:00:00");
+    assert_eq!(window.end_time_in_tz(&Utc), window.end_time());
+
+    window.start_time = Utc.with_ymd_and_hms(2025, 1, 1, 14, 0, 0).unwrap();
+    window.extend(1800);
+    let winter = window.end_time_in_tz(&New_York);
+    assert_eq!(winter.naive_local().to_string(), "2025-01-01 10:30:00");
+}
+
+#[test]
+fn test_illegal_window_transitions_are_rejected() {
+    assert!(!WindowState::Expired.can_transition_to(&WindowState::Open));
+    assert!(!WindowState::GracePeriod.can_transition_to(&WindowState::Open));
+    assert!(!WindowState::Open.can_transition_to(&WindowState::NotStarted));
+    assert!(!WindowState::NotStarted.can_transition_to(&WindowState::Expired));
+    assert!(!WindowState::Open.can_transition_to(&WindowState::Open));
+    assert!(!WindowState::Extended.can_transition_to(&WindowState::Open));
+    assert_eq!(
+        WindowState::Expired.next_states(),
+        vec![WindowState::Extended]
+    );
+
+    assert!(WindowState::Open.can_transition_to(&WindowState::Extended));
+    assert!(WindowState::GracePeriod.can_transition_to(&WindowState::Extended));
+    assert_eq!(
+        WindowState::NotStarted.next_states(),
+        vec![WindowState::Open, WindowState::Extended]
+    );
+}
+
+#[test]
+fn test_reopen_follows_legal_transitions() {
+    let start = Utc::now() - Duration::seconds(100);
+    let mut proposal = Proposal::new(
+        "Reopen".into(),
+        "".into(),
+        60,
+        DecayModel::Linear,
+        ThresholdModel::Linear(0.0, 0.5),
+    );
+    proposal.voting_window = VotingWindow::new(start, 60, 10);
+    let now = start + Duration::seconds(100);
+
+    let mut previous = proposal.voting_window.state(start);
+    assert_eq!(previous, WindowState::Open);
+    for (at, expected) in [(65, WindowState::GracePeriod), (100, WindowState::Expired)] {
+        let state = proposal.voting_window.state(start + Duration::seconds(at));
+        assert_eq!(state, expected);
+        assert!(
+            previous.can_transition_to(&state),
+            "{previous:?} -> {state:?}"
+        );
+        previous = state;
+    }
+
+    proposal.status = ProposalStatus::Expired;
+    assert!(proposal.reopen(30, now));
+    let state = proposal.voting_window.state(now);
+    assert_eq!(state, WindowState::Extended);
+    assert!(previous.can_transition_to(&state));
+
+    let after = proposal.voting_window.state(now + Duration::seconds(35));
+    assert_eq!(after, WindowState::GracePeriod);
+    assert!(state.can_transition_to(&after));
+    assert!(proposal.voting_window.extend(30));
+    let extended = proposal.voting_window.state(now + Duration::seconds(35));
+    assert_eq!(extended, WindowState::Extended);
+    assert!(after.can_transition_to(&extended));
+}
+
+#[test]
+fn test_window_lifecycle_follows_legal_transitions() {
+    let start = Utc::now() + Duration::seconds(2);
+    let mut window = VotingWindow::new(start, 10, 5);
+    let mut previous = window.state(start - Duration::seconds(2));
+    let mut seen = vec![previous.clone()];
+
+    for second in -1..25 {
+        if second == 5 {
+            window.extend(5);
+        }
+        let state = window.state(start + Duration::seconds(second));
+        if state != previous {
+            assert!(
+                previous.can_transition_to(&state),
+                "{previous:?} -> {state:?}"
+            );
+            seen.push(state.clone());
+            previous = state;
+        }
+    }
+
+    assert_eq!(
+        seen,
+        vec![
+            WindowState::NotStarted,
+            WindowState::Open,
+            WindowState::Extended,
+            WindowState::GracePeriod,
+            WindowState::Expired,
+        ]
+    );
+}